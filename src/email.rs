@@ -0,0 +1,112 @@
+//! Builds the email that is sent with a time sheet.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use lettre::message::header::{self, ContentType};
+use lettre::message::{Attachment, Mailboxes, MultiPart, SinglePart};
+use lettre::Message;
+
+use crate::input::Config;
+use crate::template::Template;
+
+/// Replaces the placeholders like `{year:02}` or `{month:de}` in the subject.
+pub fn expand_subject(subject: &str, config: &Config) -> anyhow::Result<String> {
+    Template::new(subject)
+        .render(&config.template_context())
+        .context("invalid subject")
+}
+
+fn attachment_from_file(path: impl AsRef<Path>) -> anyhow::Result<SinglePart> {
+    let path = path.as_ref();
+
+    Ok(Attachment::new(
+        path.file_name()
+            .ok_or_else(|| anyhow::anyhow!("missing file_name in path \"{}\"", path.display()))?
+            .to_str()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "failed to convert path to a unicode string: \"{}\"",
+                    path.display()
+                )
+            })?
+            .to_string(),
+    )
+    .body(fs::read(path)?, ContentType::parse("application/pdf")?))
+}
+
+/// Builds the email with the time sheet of the config as attachment.
+///
+/// The email is sent to the `recipient` or if it is `None`, to the recipients
+/// of the config. The time sheet must have been generated before.
+pub fn build_email(
+    config: &Config,
+    recipient: Option<&str>,
+    subject: &str,
+) -> anyhow::Result<Message> {
+    let mail = config
+        .mail()
+        .ok_or_else(|| anyhow::anyhow!("missing mail config in global config"))?;
+
+    let mut builder = mail.builder()?.subject(expand_subject(subject, config)?);
+
+    if let Some(recipient) = recipient {
+        builder = builder.to(recipient.parse()?);
+    } else if config.recipients().is_empty() {
+        return Err(anyhow::anyhow!(
+            "no recipients for `{}`, add them to the contract or the mail config",
+            config.department()
+        ));
+    } else {
+        for address in config.recipients() {
+            builder = builder.to(address.clone().try_into()?);
+        }
+    }
+
+    for address in config.cc() {
+        builder = builder.cc(address.clone().try_into()?);
+    }
+
+    // attach the file to the email:
+    let attachment = attachment_from_file(config.output())?;
+
+    let email = {
+        if let Some(body) = mail.body() {
+            let context = config.template_context();
+            let text = body.text(&context).context("invalid mail body")?;
+
+            let content = {
+                if let Some(html) = body.html(&context) {
+                    let html = html.context("invalid html mail body")?;
+                    MultiPart::mixed().multipart(MultiPart::alternative_plain_html(text, html))
+                } else {
+                    MultiPart::mixed().singlepart(SinglePart::plain(text))
+                }
+            };
+
+            builder.multipart(content.singlepart(attachment))?
+        } else {
+            builder.singlepart(attachment)?
+        }
+    };
+
+    Ok(email)
+}
+
+/// Returns the recipients in the `To` header of the email.
+///
+/// Unlike the envelope, this does not include the CC and BCC recipients.
+#[must_use]
+pub fn recipients_of(email: &Message) -> Vec<String> {
+    email
+        .headers()
+        .get::<header::To>()
+        .map(|to| {
+            Mailboxes::from(to)
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -5,9 +5,11 @@ use anyhow::Context;
 
 use crate::input::json_input::{Entry, GlobalFile};
 use crate::input::scheduler::SchedulerOptions;
-use crate::input::toml_input::{self, Contract, Mail};
-use crate::input::{Month, Signature};
+use crate::input::toml_input::{self, Contract, LatexOptions, Mail, MailAddress};
+use crate::input::{Month, Signature, Transfer};
 use crate::latex_string::LatexString;
+use crate::template::TemplateContext;
+use crate::time::Date;
 use crate::utils;
 use crate::working_duration;
 
@@ -19,7 +21,12 @@ pub struct Config {
     output: PathBuf,
     preserve_dir: Option<PathBuf>,
     month: Month,
-    latex_mk_path: Option<PathBuf>,
+    // the wage of the contract, `None` if it has not been set
+    wage: Option<f32>,
+    contract_start_date: Date,
+    recipients: Vec<MailAddress<'static>>,
+    cc: Vec<MailAddress<'static>>,
+    latex: LatexOptions,
 }
 
 pub struct ConfigBuilder {
@@ -28,19 +35,79 @@ pub struct ConfigBuilder {
     month: toml_input::Month,
     output: Option<PathBuf>,
     preserve_dir: Option<PathBuf>,
+    // the file name and the preserve dir with the placeholders replaced
+    default_file_name: PathBuf,
+    global_preserve_dir: Option<PathBuf>,
 }
 
 impl ConfigBuilder {
     fn new(global: toml_input::Global, month: toml_input::Month) -> anyhow::Result<Self> {
+        if month.has_department_sections() {
+            return Err(anyhow::anyhow!(
+                "the month file has sections for multiple departments, each of them needs its own config"
+            ));
+        }
+
         let department = month.general().department();
+        if department.is_empty() {
+            return Err(anyhow::anyhow!("missing department in the month file"));
+        }
+
+        let (year, month_number) = (month.general().year(), month.general().month());
         let contract = global
             .contract(department)
             .ok_or_else(|| anyhow::anyhow!("no contract for department `{}`", department))?
-            .clone();
+            .in_month(year, month_number);
+
+        if !contract.is_active_in(year, month_number) {
+            return Err(anyhow::anyhow!(
+                "the month {:04}-{:02} is outside of the contract for `{}` ({} to {})",
+                year,
+                month_number,
+                department,
+                contract.start_date(),
+                contract.end_date()
+            ));
+        }
+
+        for entry in month.entries() {
+            let date = Date::new(year, month_number, entry.day())?;
+
+            if !contract.is_active_on(date) {
+                return Err(anyhow::anyhow!(
+                    "the entry \"{}\" on {} is outside of the contract for `{}` ({} to {})",
+                    entry.action(),
+                    date,
+                    department,
+                    contract.start_date(),
+                    contract.end_date()
+                ));
+            }
+        }
+
+        if let Some(holiday) = month.holiday().filter(|holiday| holiday.is_implicit()) {
+            holiday
+                .vacation_duration(
+                    contract.vacation(),
+                    contract.expected_working_duration_in(year, month_number),
+                )
+                .with_context(|| format!("invalid `[holiday]` for `{}`", department))?;
+        }
+
+        let default_file_name = PathBuf::from(
+            global
+                .resolve_output(&month)
+                .context("invalid `output_format` in the global config")?,
+        );
+        let global_preserve_dir = global
+            .resolve_preserve_dir(&month)
+            .context("invalid `preserve_dir` in the global config")?;
 
         Ok(Self {
             output: None,
             preserve_dir: None,
+            default_file_name,
+            global_preserve_dir,
             global,
             month,
             contract,
@@ -57,19 +124,127 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn department(&self) -> &str {
+        self.contract.department()
+    }
+
+    pub fn month(&self) -> &toml_input::Month {
+        &self.month
+    }
+
+    /// Takes over the time that the `previous` month transferred to this month,
+    /// if the month file does not declare a transfer itself.
+    ///
+    /// Only the `next` of the `[transfer]` of the previous month file is taken
+    /// over, which is what its time sheet shows. The difference between the
+    /// time worked and the time expected in the previous month is not carried
+    /// over, if it has not been declared there.
+    ///
+    /// Nothing is changed if `previous` is not the month before this one or
+    /// belongs to another department.
+    pub fn chain_transfer(&mut self, previous: &Config) -> &mut Self {
+        let (year, month) = (self.month.general().year(), self.month.general().month());
+        let previous_month = previous.month();
+        let next_date = Date::last_day(previous_month.year(), previous_month.month()) + 1;
+
+        if self.month.transfer().is_none()
+            && previous.department() == self.department()
+            && (next_date.year(), next_date.month()) == (year, month)
+        {
+            self.month.set_transfer(Transfer::new(
+                previous_month.transfer().next(),
+                working_duration!(00:00),
+            ));
+        }
+
+        self
+    }
+
+    /// Builds the configs for the departments of a month file one after another.
+    ///
+    /// The explicit entries, repeating events and holidays of all contracts
+    /// are placed first, then the dynamic entries are scheduled around them,
+    /// one contract after another. This way the daily limit applies to all
+    /// contracts together and no entries overlap.
+    pub fn build_all(builders: impl IntoIterator<Item = Self>) -> anyhow::Result<Vec<Config>> {
+        let builders = builders.into_iter().collect::<Vec<_>>();
+        // the explicit entries of all contracts are known before anything is scheduled
+        let explicit_entries = builders
+            .iter()
+            .map(|builder| builder.month.entries().map(Entry::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let fixed_entries_of = |configs: &[Config]| {
+            configs
+                .iter()
+                .flat_map(|config| {
+                    config
+                        .month()
+                        .fixed_entries()
+                        .map(|(_, entry)| entry.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut configs: Vec<Config> = Vec::with_capacity(builders.len());
+
+        for (i, builder) in builders.into_iter().enumerate() {
+            let other_entries = fixed_entries_of(&configs)
+                .into_iter()
+                .chain(explicit_entries[i + 1..].iter().flatten().cloned())
+                .collect::<Vec<_>>();
+
+            let department = builder.contract.department().to_string();
+            let config = builder
+                .build_with_other_entries(other_entries)
+                .with_context(|| format!("invalid entry for the department `{}`", department))?;
+
+            config
+                .month()
+                .validate_other_entries()
+                .with_context(|| format!("invalid entry for the department `{}`", department))?;
+
+            configs.push(config);
+        }
+
+        // the dynamic entries are scheduled when the month is resolved, so they
+        // have to know the dynamic entries of the previous contracts as well
+        for i in 0..configs.len() {
+            let other_entries = configs[..i]
+                .iter()
+                .flat_map(|config| {
+                    config
+                        .month()
+                        .resolve()
+                        .entries()
+                        .map(|(_, entry)| entry.clone())
+                        .collect::<Vec<_>>()
+                })
+                .chain(fixed_entries_of(&configs[i + 1..]))
+                .collect::<Vec<_>>();
+
+            configs[i].month.set_other_entries(other_entries);
+        }
+
+        Ok(configs)
+    }
+
     #[must_use]
     pub fn build(self) -> Config {
-        let default_file_name = PathBuf::from(self.global.resolve_output(&self.month));
+        self.build_with_other_entries(Vec::new())
+            .expect("without other contracts the repeating events should not conflict")
+    }
 
+    fn build_with_other_entries(self, other_entries: Vec<Entry>) -> anyhow::Result<Config> {
         let output = {
             if let Some(output) = self.output {
                 if output.is_dir() {
-                    output.join(default_file_name)
+                    output.join(self.default_file_name)
                 } else {
                     output
                 }
             } else {
-                default_file_name
+                self.default_file_name
             }
         };
 
@@ -82,57 +257,82 @@ impl ConfigBuilder {
             .chain(self.global.dynamic_repeating_in_month(
                 self.month.general().year(),
                 self.month.general().month(),
-                |date| date.is_workday(),
+                |date| date.is_workday() && self.contract.is_active_on(date),
                 self.contract.department(),
             ))
             .collect();
 
+        let expected_working_duration = self.contract.expected_working_duration_in(
+            self.month.general().year(),
+            self.month.general().month(),
+        );
+
         let mut month = Month::new(
             self.month.general().month(),
             self.month.general().year(),
             self.month.transfer().unwrap_or_default(),
             self.month.entries().map(Entry::from).collect(),
             dynamic_entries,
-            Some(self.contract.expected_working_duration()),
+            Some(expected_working_duration),
             self.month.absences().collect::<Vec<_>>(),
             SchedulerOptions {
                 daily_limit: working_duration!(06:00),
                 strategy: self.month.general().strategy(),
+                start_date: Some(self.contract.start_date()),
+                end_date: Some(self.contract.end_date()),
                 ..Default::default()
             },
         );
+        month.add_other_entries(other_entries);
 
         for entry in self
             .global
             .repeating_in_month(
                 self.month.general().year(),
                 self.month.general().month(),
-                |date| date.is_workday(),
+                |date| date.is_workday() && self.contract.is_active_on(date),
                 self.contract.department(),
             )
             .map(Entry::from)
         {
-            month.add_entry_if_possible(entry);
+            let action = entry.action().to_string();
+            month
+                .add_entry_if_possible(entry)
+                .with_context(|| format!("failed to add the repeating event \"{}\"", action))?;
         }
 
         if let Some(holiday) = self.month.holiday() {
-            month.schedule_holiday(holiday);
+            month.schedule_holiday(holiday, self.contract.vacation());
         }
 
         let mut preserve_dir = self.preserve_dir;
 
-        if let Some(dir) = self.global.preserve_dir() {
-            preserve_dir = Some(dir.to_path_buf());
+        if let Some(dir) = self.global_preserve_dir {
+            preserve_dir = Some(dir);
         }
 
-        Config {
+        Ok(Config {
             month,
+            wage: self.contract.wage(),
+            contract_start_date: self.contract.start_date(),
+            recipients: {
+                if self.contract.recipients().is_empty() {
+                    self.global
+                        .mail()
+                        .map(|mail| mail.recipients().to_vec())
+                        .unwrap_or_default()
+                } else {
+                    self.contract.recipients().to_vec()
+                }
+            },
+            cc: self.contract.cc().to_vec(),
             mail: self.global.mail().cloned(),
             global_file: GlobalFile::from((
                 self.global.about().clone(),
                 self.contract.department().to_string(),
                 self.contract,
-            )),
+            ))
+            .with_working_time(expected_working_duration),
             signature: {
                 if let (Some(month_signature), Some(global_signature)) = (
                     self.month.general().signature(),
@@ -148,8 +348,8 @@ impl ConfigBuilder {
             },
             output,
             preserve_dir: preserve_dir,
-            latex_mk_path: self.global.latex_mk_path().map(|v| v.to_path_buf()),
-        }
+            latex: self.global.latex_options(),
+        })
     }
 }
 
@@ -165,12 +365,44 @@ impl Config {
         month: impl AsRef<Path>,
         global: impl AsRef<Path>,
     ) -> anyhow::Result<ConfigBuilder> {
+        let (month, global) = Self::read_toml_files(month, global)?;
+
+        Self::try_from_toml(month, global)
+    }
+
+    /// Returns a builder for each department in the month file.
+    ///
+    /// The configs should be built with [`ConfigBuilder::build_all`].
+    pub fn try_all_from_toml(
+        month: toml_input::Month,
+        global: toml_input::Global,
+    ) -> anyhow::Result<Vec<ConfigBuilder>> {
+        month
+            .split_by_department()?
+            .into_iter()
+            .map(|month| ConfigBuilder::new(global.clone(), month))
+            .collect()
+    }
+
+    pub fn try_all_from_toml_files(
+        month: impl AsRef<Path>,
+        global: impl AsRef<Path>,
+    ) -> anyhow::Result<Vec<ConfigBuilder>> {
+        let (month, global) = Self::read_toml_files(month, global)?;
+
+        Self::try_all_from_toml(month, global)
+    }
+
+    fn read_toml_files(
+        month: impl AsRef<Path>,
+        global: impl AsRef<Path>,
+    ) -> anyhow::Result<(toml_input::Month, toml_input::Global)> {
         let month: toml_input::Month = utils::toml_from_reader(File::open(month.as_ref())?)
             .with_context(|| format!("failed to parse `{}`", month.as_ref().display()))?;
         let global: toml_input::Global = utils::toml_from_reader(File::open(global.as_ref())?)
             .with_context(|| format!("failed to parse `{}`", global.as_ref().display()))?;
 
-        Self::try_from_toml(month, global)
+        Ok((month, global))
     }
 
     pub fn output(&self) -> &Path {
@@ -197,19 +429,67 @@ impl Config {
         &self.month
     }
 
-    pub fn latex_mk_path(&self) -> Option<&Path> {
-        self.latex_mk_path.as_deref()
+    pub fn department(&self) -> &str {
+        self.global_file().department()
+    }
+
+    /// The wage of the contract or `None` if the contract does not specify one
+    /// and the default wage is used.
+    pub fn wage(&self) -> Option<f32> {
+        self.wage
+    }
+
+    /// The start of the contract, the department can have had contracts before.
+    pub fn contract_start_date(&self) -> Date {
+        self.contract_start_date
+    }
+
+    /// The values for the placeholders of the email subject and body.
+    #[must_use]
+    pub fn template_context(&self) -> TemplateContext {
+        TemplateContext::new()
+            .with("name", self.global_file.name())
+            .with("staff_id", self.global_file.staff_id())
+            .with("year", self.month.year())
+            .with("month", self.month.month())
+            .with("department", self.department())
+            .with(
+                "total",
+                self.month.resolve().total_working_time().to_string(),
+            )
+            .with("transfer", self.month.transfer().to_string())
+    }
+
+    /// How the time sheet is rendered.
+    pub fn latex_options(&self) -> &LatexOptions {
+        &self.latex
     }
 
     pub fn mail(&self) -> Option<&Mail> {
         self.mail.as_ref()
     }
 
+    /// Who gets the time sheet, the recipients of the contract or if it has
+    /// none, the ones of the mail config.
+    pub fn recipients(&self) -> &[MailAddress<'static>] {
+        &self.recipients
+    }
+
+    /// Who gets a copy of the time sheet in addition to the copies of the
+    /// mail config.
+    pub fn cc(&self) -> &[MailAddress<'static>] {
+        &self.cc
+    }
+
     pub fn write_global_json(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
-        utils::write(path, serde_json::to_string_pretty(self.global_file())?)?;
+        utils::write(path, self.to_global_json()?)?;
         Ok(())
     }
 
+    pub fn to_global_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self.global_file())
+    }
+
     pub fn write_month_json(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
         utils::write(path, self.to_month_json()?)?;
         Ok(())
@@ -43,17 +43,30 @@ impl Entry {
 
         // automatically add pauses if they are missing:
         if pause.is_none() {
-            let duration = result.work_duration();
-            if duration >= working_duration!(09:00) {
-                result = result.with_pause(working_duration!(00:45));
-            } else if duration >= working_duration!(06:00) {
-                result = result.with_pause(working_duration!(00:30));
+            if let Some(pause) = Self::required_pause(result.work_duration()) {
+                result = result.with_pause(pause);
             }
         }
 
         result
     }
 
+    /// Returns the pause that is legally required after working for `duration`,
+    /// which is 30 minutes from 6 hours on and 45 minutes from 9 hours on.
+    ///
+    /// This is used for the pauses that are added to the entries and by the
+    /// labour law check, so that both agree.
+    #[must_use]
+    pub const fn required_pause(duration: WorkingDuration) -> Option<WorkingDuration> {
+        if duration.as_mins() >= working_duration!(09:00).as_mins() {
+            Some(working_duration!(00:45))
+        } else if duration.as_mins() >= working_duration!(06:00).as_mins() {
+            Some(working_duration!(00:30))
+        } else {
+            None
+        }
+    }
+
     pub fn new_vacation(
         action: impl ToString,
         day: usize,
@@ -257,4 +270,17 @@ mod tests {
             working_duration!(05:30),
         );
     }
+
+    #[test]
+    fn test_required_pause() {
+        assert_eq!(Entry::required_pause(working_duration!(05:59)), None);
+        assert_eq!(
+            Entry::required_pause(working_duration!(06:00)),
+            Some(working_duration!(00:30))
+        );
+        assert_eq!(
+            Entry::required_pause(working_duration!(09:30)),
+            Some(working_duration!(00:45))
+        );
+    }
 }
@@ -41,7 +41,7 @@ impl From<(toml_input::About, String, toml_input::Contract)> for GlobalFile {
             staff_id: about.staff_id(),
             department,
             working_time: contract.expected_working_duration(),
-            wage: contract.wage().unwrap_or(12.00),
+            wage: contract.wage().unwrap_or(Self::DEFAULT_WAGE),
             working_area: contract.working_area(),
             bg_content: contract
                 .bg_content()
@@ -51,6 +51,17 @@ impl From<(toml_input::About, String, toml_input::Contract)> for GlobalFile {
 }
 
 impl GlobalFile {
+    /// The wage that is used, if the contract does not specify one.
+    pub const DEFAULT_WAGE: f32 = 12.00;
+
+    /// Overrides the working time of the contract, for example if the
+    /// contract only covers a part of the month.
+    #[must_use]
+    pub fn with_working_time(mut self, working_time: WorkingDuration) -> Self {
+        self.working_time = working_time;
+        self
+    }
+
     #[must_use]
     pub fn bg_content(&self) -> Option<&LatexString> {
         self.bg_content.as_ref()
@@ -59,4 +70,24 @@ impl GlobalFile {
     pub fn expected_working_duration(&self) -> WorkingDuration {
         self.working_time
     }
+
+    #[must_use]
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn staff_id(&self) -> usize {
+        self.staff_id
+    }
+
+    #[must_use]
+    pub fn wage(&self) -> f32 {
+        self.wage
+    }
 }
@@ -1,5 +1,6 @@
 mod config;
 mod month;
+mod month_files;
 mod sign;
 mod signature;
 mod working_area;
@@ -11,6 +12,7 @@ pub mod toml_input;
 
 pub use config::*;
 pub use month::*;
+pub use month_files::*;
 pub use scheduler::Scheduler;
 pub use sign::*;
 pub use signature::*;
@@ -1,14 +1,85 @@
+use derive_more::Display;
 use log::debug;
 use serde::ser;
 use serde::Serialize;
+use thiserror::Error;
 
 use crate::input::json_input::{Entry, MonthFile};
-use crate::input::scheduler::SchedulerOptions;
-use crate::input::toml_input::{Absence, DynamicEntry, Holiday, Transfer};
+use crate::input::scheduler::{MonthScheduler, SchedulerOptions};
+use crate::input::toml_input::{Absence, DynamicEntry, Holiday, Transfer, Vacation};
 use crate::input::Task;
 use crate::time::{self, Date, TimeSpan, TimeStamp, WorkingDuration, Year};
 use crate::{time_stamp, working_duration};
 
+/// The reason why an entry can not be added to a month.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EntryConflict {
+    #[error("the entry overlaps with \"{action}\" ({span}) on {date}")]
+    Overlap {
+        date: Date,
+        action: String,
+        span: TimeSpan,
+    },
+    #[error("the entry overlaps with an absence ({span}) on {date}")]
+    Absence { date: Date, span: TimeSpan },
+    #[error("working {duration} on {date} would exceed the daily limit of {limit}")]
+    DailyLimit {
+        date: Date,
+        duration: WorkingDuration,
+        limit: WorkingDuration,
+    },
+}
+
+/// Where an entry of the time sheet comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Display)]
+pub enum EntryOrigin {
+    /// The entry is written in the month file.
+    #[display(fmt = "explicit")]
+    Explicit,
+    /// The entry is a repeating event from the global file.
+    #[display(fmt = "repeating")]
+    Repeating,
+    /// The entry has been generated for the holiday.
+    #[display(fmt = "holiday")]
+    Holiday,
+    /// The entry has been scheduled from a dynamic entry.
+    #[display(fmt = "dynamic")]
+    Dynamic,
+}
+
+/// The entries of a month after the dynamic entries have been scheduled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedMonth {
+    entries: Vec<(EntryOrigin, Entry)>,
+    transfer: Transfer,
+}
+
+impl ResolvedMonth {
+    /// Returns the entries sorted by day and time.
+    pub fn entries(&self) -> impl Iterator<Item = (EntryOrigin, &Entry)> + '_ {
+        self.entries.iter().map(|(origin, entry)| (*origin, entry))
+    }
+
+    pub fn entries_on_day(&self, date: Date) -> impl Iterator<Item = (EntryOrigin, &Entry)> + '_ {
+        self.entries()
+            .filter(move |(_, entry)| entry.day() == date.day())
+    }
+
+    /// The time that could not be scheduled in this month.
+    #[must_use]
+    pub const fn transfer(&self) -> Transfer {
+        self.transfer
+    }
+
+    #[must_use]
+    pub fn total_working_time(&self) -> WorkingDuration {
+        self.entries
+            .iter()
+            .map(|(_, entry)| entry.work_duration())
+            .sum()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Month {
     year: Year,
@@ -17,7 +88,12 @@ pub struct Month {
     expected_working_duration: Option<WorkingDuration>,
     transfer: Transfer,
     entries: Vec<Entry>,
+    // where each of the `entries` comes from
+    origins: Vec<EntryOrigin>,
     absence: Vec<(Date, Absence)>,
+    // the entries of the other contracts in this month, they are not on the
+    // time sheet, but count towards the daily limit
+    other_entries: Vec<Entry>,
     options: SchedulerOptions,
 }
 
@@ -40,29 +116,162 @@ impl Month {
             month,
             year,
             transfer,
+            origins: vec![EntryOrigin::Explicit; entries.len()],
             entries,
             dynamic_entries,
             expected_working_duration,
             absence,
+            other_entries: Vec::new(),
             options,
         }
     }
 
-    pub fn add_entry_if_possible(&mut self, entry: Entry) {
+    /// Adds the entries of the other contracts in this month, so that nothing
+    /// will be scheduled at the same time and the daily limit applies to all
+    /// contracts together.
+    pub fn add_other_entries(&mut self, entries: impl IntoIterator<Item = Entry>) {
+        self.other_entries.extend(entries);
+    }
+
+    /// Replaces the entries of the other contracts, see
+    /// [`Month::add_other_entries`].
+    pub fn set_other_entries(&mut self, entries: impl IntoIterator<Item = Entry>) {
+        self.other_entries = entries.into_iter().collect();
+    }
+
+    /// Checks that the entries of this month do not overlap with the entries of
+    /// the other contracts and that the daily limit is not exceeded.
+    pub fn validate_other_entries(&self) -> Result<(), EntryConflict> {
+        for entry in &self.entries {
+            let date = Date::new(self.year, self.month, entry.day()).unwrap();
+            let span = entry.time_span();
+
+            if let Some(other) = self.other_entries.iter().find(|other| {
+                other.day() == entry.day()
+                    && span
+                        .overlapping_duration(&other.time_span())
+                        .is_some_and(|duration| !duration.is_zero())
+            }) {
+                return Err(EntryConflict::Overlap {
+                    date,
+                    action: other.action().to_string(),
+                    span: other.time_span(),
+                });
+            }
+
+            let duration = self.working_time_on_day(date);
+            if self.other_working_time_on_day(date) > working_duration!(00:00)
+                && duration > self.maximum_work_duration()
+            {
+                return Err(EntryConflict::DailyLimit {
+                    date,
+                    duration,
+                    limit: self.maximum_work_duration(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a repeating entry.
+    ///
+    /// The entry is skipped if it does not fit in with this contract, for
+    /// example because of an absence on that day. If it conflicts with the
+    /// entries of the other contracts, an error is returned, because the
+    /// entry would be missing on the time sheet without a reason.
+    pub fn add_entry_if_possible(&mut self, entry: Entry) -> Result<(), EntryConflict> {
         let span = entry.time_span();
-        let entry_date = Date::new(self.year, self.month, entry.day()).unwrap();
+        let date = Date::new(self.year, self.month, entry.day()).unwrap();
         // TODO: is span.duration() right? This would include pauses
-        let scheduled = self.schedule(
-            Task::new_duration(span.duration())
-                .with_start(span.start())
-                .with_suggested_date(entry_date),
-        );
+        let duration = span.duration();
 
-        if let Some((date, span)) = scheduled.get(0) {
-            if *date == entry_date && *span == entry.time_span() {
-                self.entries.push(entry);
-            }
+        let conflicts_with_own = !self.options.is_in_range(date)
+            || self.own_working_time_on_day(date) + duration > self.maximum_work_duration()
+            || self
+                .entries
+                .iter()
+                .any(|other| other.day() == entry.day() && other.time_span().overlaps_with(span))
+            || self
+                .absences_on_day(date)
+                .any(|absence| absence.time_span().overlaps_with(span));
+
+        if conflicts_with_own {
+            debug!("skipping \"{}\" on {}", entry.action(), date);
+            return Ok(());
+        }
+
+        if let Some(other) = self.other_entries.iter().find(|other| {
+            other.day() == entry.day()
+                && span
+                    .overlapping_duration(&other.time_span())
+                    .is_some_and(|duration| !duration.is_zero())
+        }) {
+            return Err(EntryConflict::Overlap {
+                date,
+                action: other.action().to_string(),
+                span: other.time_span(),
+            });
         }
+
+        let total = self.working_time_on_day(date) + duration;
+        if total > self.maximum_work_duration() {
+            return Err(EntryConflict::DailyLimit {
+                date,
+                duration: total,
+                limit: self.maximum_work_duration(),
+            });
+        }
+
+        self.entries.push(entry);
+        self.origins.push(EntryOrigin::Repeating);
+
+        Ok(())
+    }
+
+    /// Checks if the entry can be added on the `date` without overlapping with
+    /// an existing entry or absence and without exceeding the daily limit.
+    ///
+    /// Entries that only touch each other (one ends when the other starts) do
+    /// not overlap.
+    pub fn validate_entry(&self, date: Date, entry: &Entry) -> Result<(), EntryConflict> {
+        let span = entry.time_span();
+        let overlaps = |other: TimeSpan| {
+            span.overlapping_duration(&other)
+                .is_some_and(|duration| !duration.is_zero())
+        };
+
+        if let Some(existing) = self
+            .entries_on_day(date)
+            .find(|existing| overlaps(existing.time_span()))
+        {
+            return Err(EntryConflict::Overlap {
+                date,
+                action: existing.action().to_string(),
+                span: existing.time_span(),
+            });
+        }
+
+        if let Some(absence) = self
+            .absences_on_day(date)
+            .find(|absence| overlaps(absence.time_span()))
+        {
+            return Err(EntryConflict::Absence {
+                date,
+                span: absence.time_span(),
+            });
+        }
+
+        let duration = self.working_time_on_day(date) + entry.work_duration();
+        if duration > self.maximum_work_duration() {
+            return Err(EntryConflict::DailyLimit {
+                date,
+                duration,
+                limit: self.maximum_work_duration(),
+            });
+        }
+
+        Ok(())
     }
 
     /// Returns the amount of time that is remaining to be worked in this month.
@@ -103,13 +312,19 @@ impl Month {
         result
     }
 
-    pub fn schedule_holiday(&mut self, holiday: &Holiday) {
-        self.entries.extend(holiday.to_entry(
+    pub fn schedule_holiday(&mut self, holiday: &Holiday, vacation: &Vacation) {
+        let entries = holiday.to_entry(
             self.year,
             self.month,
             self.real_expected_working_duration(),
+            vacation,
             |task| self.schedule(task),
-        ));
+        );
+
+        for entry in entries {
+            self.entries.push(entry);
+            self.origins.push(EntryOrigin::Holiday);
+        }
     }
 
     /// Returns the amount of time that the user should have worked in this month.
@@ -131,6 +346,11 @@ impl Month {
         self.dynamic_entries.iter()
     }
 
+    /// Returns the explicit, repeating and holiday entries of the month.
+    pub fn fixed_entries(&self) -> impl Iterator<Item = (EntryOrigin, &Entry)> + '_ {
+        self.origins.iter().copied().zip(self.entries.iter())
+    }
+
     #[must_use]
     pub fn year(&self) -> Year {
         self.year
@@ -154,6 +374,18 @@ impl Month {
             + self.transfer.previous()
     }
 
+    /// Returns how much has been worked on that day for this contract, without
+    /// the work for the other contracts.
+    pub fn own_working_time_on_day(&self, date: Date) -> WorkingDuration {
+        self.entries
+            .iter()
+            .filter(|entry| entry.day() == date.day())
+            .map(|entry| entry.work_duration())
+            .sum::<WorkingDuration>()
+    }
+
+    /// Returns how much has been worked on that day, including the work for
+    /// the other contracts.
     pub fn working_time_on_day(&self, date: Date) -> WorkingDuration {
         self.entries_on_day(date)
             .map(|e| e.work_duration())
@@ -196,6 +428,7 @@ impl Month {
     ) -> impl Iterator<Item = Date> + '_ {
         self.year()
             .days_in(self.month())
+            .filter(move |date| self.options.is_in_range(*date))
             .filter(move |date| !self.exceeds_working_duration_on_with(*date, duration))
             .filter(move |date| {
                 // remove all dates where the start + duration conflict with
@@ -209,14 +442,25 @@ impl Month {
             })
     }
 
-    /// Returns an iterator over all entries that are on the given day.
+    /// Returns an iterator over all entries that are on the given day,
+    /// including the ones of the other contracts.
     fn entries_on_day(&self, date: Date) -> impl Iterator<Item = &Entry> + '_ {
         self.entries
             .iter()
+            .chain(self.other_entries.iter())
             .filter(move |entry| entry.day() == date.day())
     }
 
-    fn absences_on_day(&self, date: Date) -> impl Iterator<Item = &Absence> + '_ {
+    /// Returns how much has been worked for the other contracts on that day.
+    pub fn other_working_time_on_day(&self, date: Date) -> WorkingDuration {
+        self.other_entries
+            .iter()
+            .filter(|entry| entry.day() == date.day())
+            .map(|entry| entry.work_duration())
+            .sum::<WorkingDuration>()
+    }
+
+    pub fn absences_on_day(&self, date: Date) -> impl Iterator<Item = &Absence> + '_ {
         self.absence
             .iter()
             .filter_map(move |(d, absence)| (*d == date).then_some(absence))
@@ -231,12 +475,32 @@ impl Month {
     /// Returns the transfer time for the month.
     /// (how much time is transfered to the next month/from the previous month)
     #[must_use]
-    const fn transfer(&self) -> Transfer {
+    pub const fn transfer(&self) -> Transfer {
         self.transfer
     }
 
-    fn to_month_file(&self) -> MonthFile {
-        let mut entries = self.entries.clone();
+    #[must_use]
+    pub const fn options(&self) -> &SchedulerOptions {
+        &self.options
+    }
+
+    /// Returns how much time should be worked in the given week of the month.
+    ///
+    /// The expected working duration is distributed over the workdays of the
+    /// month, like it is done when scheduling the dynamic entries.
+    #[must_use]
+    pub fn capacity_of_week(&self, week_number: usize) -> WorkingDuration {
+        MonthScheduler::for_month(self, &self.options).available_time_in_week(week_number)
+    }
+
+    /// Schedules the dynamic entries and returns all entries of the month,
+    /// together with where they come from.
+    #[must_use]
+    pub fn resolve(&self) -> ResolvedMonth {
+        let mut entries = self
+            .fixed_entries()
+            .map(|(origin, entry)| (origin, entry.clone()))
+            .collect::<Vec<_>>();
 
         let mut mapping = Vec::with_capacity(self.dynamic_entries.len());
         let mut durations = Vec::with_capacity(mapping.capacity());
@@ -249,17 +513,32 @@ impl Month {
 
         let distribution = DynamicEntry::distribute(durations.into_iter(), self, &self.options);
 
-        debug!("transfer: {:?}", distribution.transfer_time());
+        let transfer = distribution.transfer_time();
+        debug!("transfer: {:?}", transfer);
         // TODO: what to do with the transfer_tasks and transfer?
 
         for (id, time) in distribution.schedule() {
             let dynamic_entry = mapping[id];
 
-            entries.push(dynamic_entry.to_entry(Self::DEFAULT_START, time));
+            entries.push((
+                EntryOrigin::Dynamic,
+                dynamic_entry.to_entry(Self::DEFAULT_START, time),
+            ));
         }
 
         // sort the entries in the json file, so that no problems occur with the java tool
-        entries.sort();
+        entries.sort_by(|(_, left), (_, right)| left.cmp(right));
+
+        ResolvedMonth { entries, transfer }
+    }
+
+    fn to_month_file(&self) -> MonthFile {
+        let entries = self
+            .resolve()
+            .entries
+            .into_iter()
+            .map(|(_, entry)| entry)
+            .collect();
 
         MonthFile::new(self.year, self.month, self.transfer(), entries)
     }
@@ -0,0 +1,50 @@
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use log::warn;
+
+use crate::input::toml_input;
+use crate::utils::{self, PathExt};
+
+/// Returns the month files in the directory, sorted by their path.
+///
+/// Hidden files like the running session or the send log and the global file
+/// are skipped, as well as all toml files that are not month files.
+pub fn month_files_in(
+    directory: impl AsRef<Path>,
+    global: impl AsRef<Path>,
+) -> anyhow::Result<Vec<(PathBuf, toml_input::Month)>> {
+    let directory = directory.as_ref();
+    let global = dunce::canonicalize(global)?;
+
+    let mut result = Vec::new();
+    for entry in fs::read_dir(directory)
+        .with_context(|| format!("failed to read `{}`", directory.display()))?
+    {
+        let path = dunce::canonicalize(entry?.path())?;
+
+        let is_hidden = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.starts_with('.'));
+
+        if is_hidden || !path.is_file() || !path.has_extension("toml") || path == global {
+            continue;
+        }
+
+        match utils::toml_from_reader::<_, toml_input::Month>(File::open(&path)?) {
+            Ok(month) => result.push((path, month)),
+            Err(error) => warn!(
+                "skipping `{}`, because it is not a month file: {}",
+                path.display(),
+                error
+            ),
+        }
+    }
+
+    result.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    Ok(result)
+}
@@ -0,0 +1,69 @@
+use crate::input::scheduler::{Scheduler, SchedulerOptions};
+use crate::time::{Date, WorkingDuration};
+use crate::working_duration;
+
+/// A scheduler that only schedules work between the start and end date of
+/// the options (for example the dates covered by the contract).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateRangeScheduler {
+    options: SchedulerOptions,
+}
+
+impl DateRangeScheduler {
+    #[must_use]
+    pub const fn new(options: &SchedulerOptions) -> Self {
+        Self { options: *options }
+    }
+}
+
+impl Scheduler for DateRangeScheduler {
+    fn has_time_for(&self, date: Date, wanted_duration: WorkingDuration) -> WorkingDuration {
+        if self.options.is_in_range(date) {
+            wanted_duration
+        } else {
+            working_duration!(00:00)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::date;
+
+    #[test]
+    fn test_has_time_for() {
+        let scheduler = DateRangeScheduler::new(&SchedulerOptions {
+            start_date: Some(date!(2022:12:05)),
+            end_date: Some(date!(2022:12:20)),
+            ..Default::default()
+        });
+
+        let duration = working_duration!(02:00);
+        assert_eq!(
+            scheduler.has_time_for(date!(2022:12:04), duration),
+            working_duration!(00:00)
+        );
+        assert_eq!(
+            scheduler.has_time_for(date!(2022:12:05), duration),
+            duration
+        );
+        assert_eq!(
+            scheduler.has_time_for(date!(2022:12:20), duration),
+            duration
+        );
+        assert_eq!(
+            scheduler.has_time_for(date!(2022:12:21), duration),
+            working_duration!(00:00)
+        );
+
+        let scheduler = DateRangeScheduler::new(&SchedulerOptions::default());
+        assert_eq!(
+            scheduler.has_time_for(date!(2022:12:21), duration),
+            duration
+        );
+    }
+}
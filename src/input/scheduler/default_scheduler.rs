@@ -1,11 +1,10 @@
 use crate::input::scheduler::{
-    AbsenceScheduler, DailyLimiter, FixedScheduler, MonthScheduler, Scheduler, SchedulerOptions,
-    WorkdayScheduler,
+    AbsenceScheduler, DailyLimiter, DateRangeScheduler, FixedScheduler, MonthScheduler, Scheduler,
+    SchedulerOptions, WorkdayScheduler,
 };
 use crate::input::Month;
 use crate::input::Transfer;
 use crate::time::{Date, WorkingDuration};
-use crate::working_duration;
 
 #[derive(Debug, Clone)]
 pub struct DefaultScheduler<F> {
@@ -14,6 +13,7 @@ pub struct DefaultScheduler<F> {
         FixedScheduler<F>,
         AbsenceScheduler<F>,
         DailyLimiter,
+        DateRangeScheduler,
     ),
     month_scheduler: MonthScheduler,
 }
@@ -27,21 +27,9 @@ impl<'a> DefaultScheduler<Box<dyn Fn(Date) -> WorkingDuration + 'a>> {
                 FixedScheduler::new(Box::new(|date| month.working_time_on_day(date)), options),
                 AbsenceScheduler::new(Box::new(|date| month.absence_time_on_day(date)), options),
                 DailyLimiter::new(options),
+                DateRangeScheduler::new(options),
             ),
-            month_scheduler: MonthScheduler::new_with_available_time(
-                month.year(),
-                month.month(),
-                month.expected_working_duration(),
-                |date| {
-                    if date.is_workday() {
-                        options
-                            .daily_limit
-                            .saturating_sub(month.absence_time_on_day(date))
-                    } else {
-                        working_duration!(00:00)
-                    }
-                },
-            ),
+            month_scheduler: MonthScheduler::for_month(month, options),
         }
     }
 }
@@ -1,5 +1,6 @@
 mod absence_scheduler;
 mod daily_limiter;
+mod date_range_scheduler;
 mod default_scheduler;
 mod fixed_scheduler;
 mod month_scheduler;
@@ -10,6 +11,7 @@ mod workday_scheduler;
 
 pub use absence_scheduler::*;
 pub use daily_limiter::*;
+pub use date_range_scheduler::*;
 pub use default_scheduler::*;
 pub use fixed_scheduler::*;
 pub use month_scheduler::*;
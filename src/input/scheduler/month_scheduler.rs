@@ -1,6 +1,7 @@
 use log::debug;
 
-use crate::input::scheduler::{Scheduler, TimeSpanScheduler};
+use crate::input;
+use crate::input::scheduler::{Scheduler, SchedulerOptions, TimeSpanScheduler};
 use crate::input::toml_input::Transfer;
 use crate::time::{Date, DurationExt, Month, WorkingDuration, Year};
 use crate::utils::{self, ArrayExt};
@@ -47,13 +48,43 @@ impl MonthScheduler {
         // Returns how much time is available on the given date
         // This can be used to indicate through absences or holidays
         // that less time is available on a given date.
-        mut available_time: impl FnMut(Date) -> WorkingDuration,
+        available_time: impl FnMut(Date) -> WorkingDuration,
     ) -> Self {
         debug!(
             "MonthScheduler: maximum working time per month: {}",
             maximum_time
         );
 
+        let distribution = Self::distribute_over_days(year, month, maximum_time, available_time);
+
+        Self {
+            weeks: Self::make_scheduler(year, month, |week_number| {
+                let result = distribution
+                    .iter()
+                    .filter(|(date, _)| date.week_number() == week_number)
+                    .map(|(_, duration)| *duration)
+                    .sum::<WorkingDuration>();
+
+                debug!("week {} should have a duration of {}", week_number, result);
+
+                result
+            }),
+            current_week: 0,
+        }
+    }
+
+    /// Distributes the `maximum_time` over the days of the month, proportionally
+    /// to the time that is available on each day.
+    ///
+    /// The minutes that can not be distributed evenly are added to the last day
+    /// of the week in the middle of the month.
+    #[must_use]
+    pub fn distribute_over_days(
+        year: Year,
+        month: Month,
+        maximum_time: WorkingDuration,
+        mut available_time: impl FnMut(Date) -> WorkingDuration,
+    ) -> Vec<(Date, WorkingDuration)> {
         let mut iter = year.days_in(month);
 
         let workday_distribution = [(); 31].map(|_| {
@@ -71,27 +102,75 @@ impl MonthScheduler {
         );
 
         let week_with_remainder = (year.number_of_weeks_in_month(month) + 1) / 2;
+        let day_with_remainder = year
+            .days_in_week(month, week_with_remainder)
+            .map(|days| days.end().day());
 
-        Self {
-            weeks: Self::make_scheduler(year, month, |week_number| {
-                let mut result = working_duration!(00:00);
+        year.days_in(month)
+            .map(|date| {
+                let mut minutes = distribution[date.day() - 1];
 
-                for day in year.days_in_week(month, week_number).into_iter().flatten() {
-                    result += WorkingDuration::from_mins(distribution[day.day() - 1] as u16);
+                if Some(date.day()) == day_with_remainder {
+                    minutes += remainder;
                 }
 
-                if week_number == week_with_remainder {
-                    result += WorkingDuration::from_mins(remainder as u16);
-                }
+                (date, WorkingDuration::from_mins(minutes as u16))
+            })
+            .collect()
+    }
 
-                debug!("week {} should have a duration of {}", week_number, result);
+    /// Distributes the expected working duration of the month over the workdays
+    /// in the range of the options, without exceeding the daily limit minus the
+    /// absences and the work for other contracts on that day.
+    #[must_use]
+    pub fn for_month(month: &input::Month, options: &SchedulerOptions) -> Self {
+        Self::new_with_available_time(
+            month.year(),
+            month.month(),
+            month.expected_working_duration(),
+            |date| Self::available_time_on(month, options, date),
+        )
+    }
 
-                result
-            }),
-            current_week: 0,
+    /// Returns how much of the expected working duration of the month should be
+    /// worked on each day, like it is distributed by [`MonthScheduler::for_month`].
+    #[must_use]
+    pub fn expected_per_day(
+        month: &input::Month,
+        options: &SchedulerOptions,
+    ) -> Vec<(Date, WorkingDuration)> {
+        Self::distribute_over_days(
+            month.year(),
+            month.month(),
+            month.expected_working_duration(),
+            |date| Self::available_time_on(month, options, date),
+        )
+    }
+
+    fn available_time_on(
+        month: &input::Month,
+        options: &SchedulerOptions,
+        date: Date,
+    ) -> WorkingDuration {
+        if date.is_workday() && options.is_in_range(date) {
+            options
+                .daily_limit
+                .saturating_sub(month.absence_time_on_day(date))
+                .saturating_sub(month.other_working_time_on_day(date))
+        } else {
+            working_duration!(00:00)
         }
     }
 
+    /// Returns how much time is still available in the week with the given
+    /// `week_number` (starting at 1).
+    #[must_use]
+    pub fn available_time_in_week(&self, week_number: usize) -> WorkingDuration {
+        self.weeks
+            .get(week_number.wrapping_sub(1))
+            .map_or(working_duration!(00:00), TimeSpanScheduler::remaining_time)
+    }
+
     fn transfer_from_week_to_week(&self, from: usize, to: usize) -> [TimeSpanScheduler; 6] {
         let mut result = self.weeks.clone();
 
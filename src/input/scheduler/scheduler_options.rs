@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use serde::Deserialize;
 
-use crate::time::WorkingDuration;
+use crate::time::{Date, WorkingDuration};
 use crate::working_duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
@@ -49,6 +49,19 @@ pub struct SchedulerOptions {
     pub daily_limit: WorkingDuration,
     /// The strategy to use for scheduling tasks.
     pub strategy: Strategy,
+    /// No tasks will be scheduled before this date.
+    pub start_date: Option<Date>,
+    /// No tasks will be scheduled after this date.
+    pub end_date: Option<Date>,
+}
+
+impl SchedulerOptions {
+    /// Checks if the date is between the start and end date.
+    #[must_use]
+    pub fn is_in_range(&self, date: Date) -> bool {
+        self.start_date.is_none_or(|start| start <= date)
+            && self.end_date.is_none_or(|end| date <= end)
+    }
 }
 
 impl Default for SchedulerOptions {
@@ -58,6 +71,8 @@ impl Default for SchedulerOptions {
             should_schedule_with_absences: false,
             daily_limit: working_duration!(06:00),
             strategy: Default::default(),
+            start_date: None,
+            end_date: None,
         }
     }
 }
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::input::toml_input::{MailAddress, ProRating, Vacation};
 use crate::input::WorkingArea;
-use crate::time::{Date, WorkingDuration};
+use crate::time::{Date, Month, WorkingDuration, Year};
 use crate::utils::{self, MapEntry};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,6 +17,39 @@ pub struct Contract {
     #[serde(with = "utils::serde_toml_local_date")]
     end_date: Date,
     bg_content: Option<String>,
+    #[serde(default)]
+    pro_rating: ProRating,
+    #[serde(default)]
+    periods: Vec<ContractPeriod>,
+    #[serde(default)]
+    vacation: Vacation,
+    /// Who gets the time sheets of this contract, overrides the recipients of
+    /// the mail config.
+    #[serde(default)]
+    recipients: Vec<MailAddress<'static>>,
+    /// Who gets a copy of the time sheets of this contract, in addition to the
+    /// copies of the mail config.
+    #[serde(default)]
+    cc: Vec<MailAddress<'static>>,
+}
+
+/// A change of the contract (e.g. an extension with more hours), that applies
+/// from the month of its `start_date` until the next period starts.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContractPeriod {
+    #[serde(with = "utils::serde_toml_local_date")]
+    start_date: Date,
+    working_time: Option<WorkingDuration>,
+    area: Option<WorkingArea>,
+    wage: Option<f32>,
+    bg_content: Option<String>,
+}
+
+impl ContractPeriod {
+    /// From when on the period applies.
+    pub fn start_date(&self) -> Date {
+        self.start_date
+    }
 }
 
 impl Contract {
@@ -24,11 +58,81 @@ impl Contract {
         &self.department
     }
 
+    /// Returns the contract with the values of the periods that apply to the
+    /// given month.
+    ///
+    /// A period applies to the whole month in which it starts. Values that are
+    /// not set in a period are taken from the previous periods or the contract.
+    #[must_use]
+    pub fn in_month(&self, year: Year, month: Month) -> Self {
+        let last_day = Date::last_day(year, month);
+        let mut periods = self
+            .periods
+            .iter()
+            .filter(|period| period.start_date <= last_day)
+            .collect::<Vec<_>>();
+        periods.sort_by_key(|period| period.start_date);
+
+        let mut result = self.clone();
+        for period in periods {
+            if let Some(working_time) = period.working_time {
+                result.working_time = working_time;
+            }
+
+            if let Some(area) = period.area {
+                result.area = area;
+            }
+
+            if let Some(wage) = period.wage {
+                result.wage = Some(wage);
+            }
+
+            if let Some(bg_content) = &period.bg_content {
+                result.bg_content = Some(bg_content.clone());
+            }
+        }
+
+        result
+    }
+
+    /// The periods in which the contract has been changed.
+    pub fn periods(&self) -> &[ContractPeriod] {
+        &self.periods
+    }
+
     /// How long the employee has to work each month.
     pub fn expected_working_duration(&self) -> WorkingDuration {
         self.working_time
     }
 
+    /// How long the employee has to work in the given month.
+    ///
+    /// If the contract starts or ends in that month, the working time is
+    /// reduced proportionally to the days that are covered by the contract.
+    #[must_use]
+    pub fn expected_working_duration_in(&self, year: Year, month: Month) -> WorkingDuration {
+        let days = year
+            .days_in(month)
+            .filter(|date| self.pro_rating.counts(*date));
+
+        let (covered, total) = days.fold((0, 0), |(covered, total), date| {
+            (covered + usize::from(self.is_active_on(date)), total + 1)
+        });
+
+        if covered == total || total == 0 {
+            return self.working_time;
+        }
+
+        let mins = self.working_time.as_mins() as usize;
+        WorkingDuration::from_mins(((mins * covered + total / 2) / total) as u16)
+    }
+
+    /// How the working time is reduced in months that are only partially
+    /// covered by the contract.
+    pub fn pro_rating(&self) -> ProRating {
+        self.pro_rating
+    }
+
     /// In which field the employee is working at the university.
     pub fn working_area(&self) -> WorkingArea {
         self.area
@@ -39,6 +143,19 @@ impl Contract {
         self.wage
     }
 
+    /// The vacation entitlement of the contract.
+    pub fn vacation(&self) -> &Vacation {
+        &self.vacation
+    }
+
+    pub fn recipients(&self) -> &[MailAddress<'static>] {
+        &self.recipients
+    }
+
+    pub fn cc(&self) -> &[MailAddress<'static>] {
+        &self.cc
+    }
+
     /// When the contract starts.
     pub fn start_date(&self) -> Date {
         self.start_date
@@ -49,6 +166,19 @@ impl Contract {
         self.end_date
     }
 
+    /// Checks if the contract is active on the given date.
+    #[must_use]
+    pub fn is_active_on(&self, date: Date) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+
+    /// Checks if the contract is active on at least one day of the month.
+    #[must_use]
+    pub fn is_active_in(&self, year: Year, month: Month) -> bool {
+        self.start_date <= Date::last_day(year, month)
+            && Date::first_day(year, month) <= self.end_date
+    }
+
     /// In the bottom left of the final PDF is a small signature.
     ///
     /// If this is set, then the signature will be replaced with this text.
@@ -174,8 +174,10 @@ impl DynamicEntry {
         for (_, week_dates) in month.year().iter_weeks_in(month.month()) {
             let schedule = WorkSchedule::new(*week_dates.start(), *week_dates.end());
 
+            // the work for other contracts does not count towards the weekly
+            // time of this contract
             let scheduled_tasks = schedule.schedule(&mut strategy, &mut scheduler, |date| {
-                month.working_time_on_day(date)
+                month.own_working_time_on_day(date)
             });
 
             result.extend(scheduled_tasks);
@@ -7,6 +7,7 @@ use crate::time::{Date, Month, Year};
 pub struct General {
     month: Month,
     year: Year,
+    #[serde(default)]
     department: String,
     signature: Option<GeneralSignature>,
     #[serde(default)]
@@ -37,10 +38,20 @@ impl General {
         self.signature.as_ref()
     }
 
+    /// The department of the month, which is empty if the month file only has
+    /// sections for each department.
     pub fn department(&self) -> &str {
         &self.department
     }
 
+    #[must_use]
+    pub fn with_department(&self, department: &str) -> Self {
+        Self {
+            department: department.to_string(),
+            ..self.clone()
+        }
+    }
+
     pub const fn strategy(&self) -> Strategy {
         self.strategy
     }
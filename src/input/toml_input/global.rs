@@ -2,14 +2,17 @@ use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
-use crate::input::toml_input::{self, About, Contract, DynamicEntry, Entry, Mail, RepeatingEvent};
+use crate::input::toml_input::{
+    self, About, Contract, DynamicEntry, Entry, LatexOptions, Mail, RepeatingEvent,
+};
+use crate::template::{Template, TemplateContext, TemplateError};
 use crate::time::{Date, Month, Year};
 use crate::utils;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
-    #[serde(default)]
-    latex_mk_path: Option<PathBuf>,
+    #[serde(flatten)]
+    latex: LatexOptions,
     #[serde(default)]
     output_format: Option<String>,
     #[serde(default)]
@@ -33,6 +36,10 @@ impl Global {
         &self.about
     }
 
+    pub fn contracts(&self) -> impl Iterator<Item = &Contract> + '_ {
+        self.contract.iter()
+    }
+
     #[must_use]
     pub fn contract(&self, department: &str) -> Option<&Contract> {
         self.contract
@@ -41,10 +48,11 @@ impl Global {
     }
 
     #[must_use]
-    pub fn latex_mk_path(&self) -> Option<&Path> {
+    pub fn latex_options(&self) -> LatexOptions {
         self.config
             .as_ref()
-            .and_then(|config| config.latex_mk_path.as_deref())
+            .map(|config| config.latex.clone())
+            .unwrap_or_default()
     }
 
     #[must_use]
@@ -83,27 +91,53 @@ impl Global {
             .flat_map(move |event| event.to_dynamic_entries(year, month, department, can_repeat_on))
     }
 
+    /// The values for the placeholders of the file names.
     #[must_use]
-    pub fn resolve_output(&self, month: &toml_input::Month) -> String {
+    pub fn template_context(&self, month: &toml_input::Month) -> TemplateContext {
+        TemplateContext::new()
+            .with("name", self.about.name())
+            .with("staff_id", self.about.staff_id())
+            .with("year", month.general().year())
+            .with("month", month.general().month())
+            .with("department", month.general().department())
+    }
+
+    pub fn resolve_output(&self, month: &toml_input::Month) -> Result<String, TemplateError> {
         let format = self
             .config
             .as_ref()
-            .and_then(|c| c.output_format.as_ref())
-            .map_or_else(
-                || {
-                    format!(
-                        "{year:04}-{month:02}",
-                        year = month.general().year(),
-                        month = month.general().month()
-                    )
-                },
-                |f| {
-                    f.replace("{year}", &month.general().year().to_string())
-                        .replace("{month}", &month.general().month().to_string())
-                },
-            );
-
-        format!("{}.pdf", format)
+            .and_then(|c| c.output_format.as_deref())
+            .unwrap_or("{year:04}-{month:02}");
+
+        let file_name = Template::new(format).render(&self.template_context(month))?;
+        let file_name = {
+            if !format.contains("{department") && month.is_section() {
+                // each department of the month file needs its own pdf
+                format!("{}-{}", file_name, month.general().department())
+            } else {
+                file_name
+            }
+        };
+
+        Ok(format!("{}.pdf", file_name))
+    }
+
+    /// The `preserve_dir` of the config with the placeholders replaced.
+    pub fn resolve_preserve_dir(
+        &self,
+        month: &toml_input::Month,
+    ) -> Result<Option<PathBuf>, TemplateError> {
+        let Some(dir) = self.preserve_dir() else {
+            return Ok(None);
+        };
+
+        match dir.to_str() {
+            Some(dir) => Ok(Some(PathBuf::from(
+                Template::new(dir).render(&self.template_context(month))?,
+            ))),
+            // a path that is not valid unicode can not contain placeholders
+            None => Ok(Some(dir.to_path_buf())),
+        }
     }
 
     #[must_use]
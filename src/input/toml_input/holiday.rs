@@ -1,9 +1,10 @@
+use log::info;
 use serde::Deserialize;
 
 use crate::input::json_input::Entry;
+use crate::input::toml_input::Vacation;
 use crate::input::Task;
 use crate::time::{Date, Month, TimeSpan, TimeStamp, WorkingDuration, Year};
-use crate::working_duration;
 
 const fn default_months() -> usize {
     1
@@ -29,18 +30,58 @@ const fn divide_and_round(dividend: usize, divisor: usize) -> usize {
 }
 
 impl Holiday {
-    const MAXIMUM_HOURS_PER_MONTH: WorkingDuration = working_duration!(85:00);
-    const HOLIDAYS_PER_YEAR: usize = 20;
-    const SCALAR_IN_MINS: usize = 237; // 3.95h * 60min
-
-    #[must_use]
-    const fn duration(monthly_time: WorkingDuration, months: usize) -> WorkingDuration {
+    fn duration(
+        vacation: &Vacation,
+        monthly_time: WorkingDuration,
+        months: usize,
+    ) -> anyhow::Result<WorkingDuration> {
         let mins_per_month = monthly_time.as_mins() as usize;
+        let hours_per_day_in_mins = (vacation.hours_per_day() * 60.0).round() as usize;
+        let maximum_mins_per_month = (vacation.maximum_hours_per_month() * 60.0).round() as usize;
+
+        let dividend = [vacation.days_per_year(), hours_per_day_in_mins, months]
+            .into_iter()
+            .try_fold(mins_per_month, usize::checked_mul);
+        let divisor = maximum_mins_per_month
+            .checked_mul(12)
+            .filter(|divisor| *divisor > 0);
+
+        let (Some(dividend), Some(divisor)) = (dividend, divisor) else {
+            return Err(anyhow::anyhow!(
+                "the vacation can not be calculated with {} days * {}h * {} month(s) / ({}h * 12)",
+                vacation.days_per_year(),
+                vacation.hours_per_day(),
+                months,
+                vacation.maximum_hours_per_month()
+            ));
+        };
+
+        let mins = u16::try_from(divide_and_round(dividend, divisor))
+            .ok()
+            .filter(|mins| mins / 60 <= 99)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "the vacation of {} minutes does not fit on a time sheet",
+                    divide_and_round(dividend, divisor)
+                )
+            })?;
+
+        Ok(WorkingDuration::from_mins(mins))
+    }
 
-        let dividend = mins_per_month * Self::HOLIDAYS_PER_YEAR * Self::SCALAR_IN_MINS * months;
-        let divisor = Self::MAXIMUM_HOURS_PER_MONTH.as_mins() as usize * 12;
+    /// How long the vacation is for the time that has to be worked in a month.
+    pub fn vacation_duration(
+        &self,
+        vacation: &Vacation,
+        monthly_time: WorkingDuration,
+    ) -> anyhow::Result<WorkingDuration> {
+        Self::duration(vacation, monthly_time, self.months)
+    }
 
-        WorkingDuration::from_mins(divide_and_round(dividend, divisor) as u16)
+    /// For how many months the vacation is taken.
+    #[must_use]
+    pub const fn months(&self) -> usize {
+        self.months
     }
 
     #[must_use]
@@ -54,13 +95,25 @@ impl Holiday {
         year: Year,
         month: Month,
         monthly_time: WorkingDuration,
+        vacation: &Vacation,
         mut schedule: impl FnMut(Task) -> Vec<(Date, TimeSpan)>,
     ) -> Vec<Entry> {
         if !self.implicit {
             return vec![];
         }
 
-        let duration = Self::duration(monthly_time, self.months);
+        let duration = self
+            .vacation_duration(vacation, monthly_time)
+            .expect("the vacation should have been checked when creating the config");
+        info!(
+            "vacation: {} * {} days * {}h * {} month(s) / ({}h * 12) = {}",
+            monthly_time,
+            vacation.days_per_year(),
+            vacation.hours_per_day(),
+            self.months,
+            vacation.maximum_hours_per_month(),
+            duration
+        );
 
         let date = Date::new(year, month, self.day).expect("invalid day for month");
         schedule({
@@ -84,12 +137,32 @@ mod tests {
 
     use pretty_assertions::assert_eq;
 
+    use crate::working_duration;
+
     #[test]
     fn test_calculate_duration() {
         assert_eq!(
-            Holiday::duration(working_duration!(40:00), 5),
+            Holiday::duration(&Vacation::default(), working_duration!(40:00), 5).unwrap(),
             working_duration!(15:29)
         );
+        assert_eq!(
+            Holiday::duration(&Vacation::new(30, 7.8, 167.4), working_duration!(40:00), 1).unwrap(),
+            working_duration!(04:40)
+        );
+    }
+
+    #[test]
+    fn test_invalid_duration() {
+        assert!(
+            Holiday::duration(&Vacation::new(20, 3.95, 0.0), working_duration!(40:00), 1).is_err()
+        );
+        assert!(
+            Holiday::duration(&Vacation::new(365, 24.0, 1.0), working_duration!(99:00), 12)
+                .is_err()
+        );
+        assert!(
+            Holiday::duration(&Vacation::default(), working_duration!(40:00), usize::MAX).is_err()
+        );
     }
 
     #[test]
@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use derive_more::Display;
+use serde::Deserialize;
+
+/// The program that compiles the LaTeX files to pdfs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LatexEngine {
+    #[default]
+    #[display(fmt = "xelatex")]
+    Xelatex,
+    #[display(fmt = "lualatex")]
+    Lualatex,
+    #[display(fmt = "pdflatex")]
+    Pdflatex,
+    /// Downloads the packages it needs on its own and does not need latexmk.
+    #[display(fmt = "tectonic")]
+    Tectonic,
+}
+
+impl LatexEngine {
+    /// Whether the engine is run through latexmk.
+    #[must_use]
+    pub const fn uses_latex_mk(&self) -> bool {
+        !matches!(self, Self::Tectonic)
+    }
+
+    /// The program that is run, if no path has been configured.
+    #[must_use]
+    pub const fn default_program(&self) -> &'static str {
+        if self.uses_latex_mk() {
+            "latexmk"
+        } else {
+            "tectonic"
+        }
+    }
+}
+
+/// How the pdfs are rendered, configured in the `[config]` section of the
+/// global file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct LatexOptions {
+    #[serde(default)]
+    engine: LatexEngine,
+    /// Path to latexmk or to tectonic, if that is the engine.
+    #[serde(default)]
+    latex_mk_path: Option<PathBuf>,
+    /// Arguments that are passed to latexmk or tectonic in addition to the
+    /// default ones.
+    #[serde(default)]
+    latex_args: Vec<String>,
+    /// After how many seconds the engine is stopped.
+    #[serde(default)]
+    latex_timeout: Option<u64>,
+}
+
+impl LatexOptions {
+    #[must_use]
+    pub fn engine(&self) -> LatexEngine {
+        self.engine
+    }
+
+    #[must_use]
+    pub fn latex_mk_path(&self) -> Option<&Path> {
+        self.latex_mk_path.as_deref()
+    }
+
+    /// The path to the program that is run, either the configured one or the
+    /// default of the engine.
+    #[must_use]
+    pub fn program(&self) -> PathBuf {
+        self.latex_mk_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(self.engine.default_program()))
+    }
+
+    #[must_use]
+    pub fn args(&self) -> &[String] {
+        &self.latex_args
+    }
+
+    #[must_use]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.latex_timeout.map(Duration::from_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let options: LatexOptions = toml::from_str(concat!(
+            "engine = \"tectonic\"\n",
+            "latex_args = [\"--print\"]\n",
+            "latex_timeout = 60\n",
+        ))
+        .expect("toml should be valid");
+
+        assert_eq!(options.engine(), LatexEngine::Tectonic);
+        assert_eq!(options.program(), PathBuf::from("tectonic"));
+        assert_eq!(options.args(), ["--print".to_string()]);
+        assert_eq!(options.timeout(), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_default_options() {
+        let options: LatexOptions = toml::from_str("").expect("toml should be valid");
+
+        assert_eq!(options, LatexOptions::default());
+        assert_eq!(options.engine(), LatexEngine::Xelatex);
+        assert_eq!(options.program(), PathBuf::from("latexmk"));
+        assert_eq!(options.timeout(), None);
+    }
+}
@@ -1,16 +1,45 @@
+use core::fmt;
 use std::borrow::Cow;
 
+#[cfg(feature = "lettre")]
+use lettre::address::AddressError;
+#[cfg(feature = "lettre")]
 use lettre::message::{Mailbox, MessageBuilder};
-use lettre::transport::smtp::authentication::Credentials;
+#[cfg(feature = "lettre")]
 use lettre::transport::smtp::SmtpTransport;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "lettre")]
+use thiserror::Error;
 
-#[derive(Deserialize, Debug, Clone)]
+use crate::input::toml_input::Smtp;
+use crate::template::{Template, TemplateContext, TemplateError};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct MailAddress<'a> {
     name: Cow<'a, str>,
+    #[serde(deserialize_with = "deserialize_email")]
     email: Cow<'a, str>,
 }
 
+/// Checks the address when the config is parsed, so that an invalid address
+/// is reported before anything is sent.
+fn deserialize_email<'de, 'a, D>(deserializer: D) -> Result<Cow<'a, str>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let email = String::deserialize(deserializer)?;
+
+    #[cfg(feature = "lettre")]
+    if let Err(error) = email.parse::<lettre::Address>() {
+        return Err(serde::de::Error::custom(format!(
+            "invalid email address `{}`: {}",
+            email, error
+        )));
+    }
+
+    Ok(Cow::Owned(email))
+}
+
 impl<'a> MailAddress<'a> {
     pub fn new(name: impl Into<Cow<'a, str>>, email: impl Into<Cow<'a, str>>) -> Self {
         Self {
@@ -20,59 +49,245 @@ impl<'a> MailAddress<'a> {
     }
 }
 
-impl<'a> From<MailAddress<'a>> for Mailbox {
-    fn from(MailAddress { name, email }: MailAddress<'a>) -> Self {
-        Self::new(
-            Some(name.into_owned()),
-            email.parse().expect("Mail should be valid"),
-        )
+impl<'a> fmt::Display for MailAddress<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Smtp {
-    url: String,
-    username: String,
-    password: String,
+#[cfg(feature = "lettre")]
+#[derive(Debug, Error)]
+#[error("invalid email address in `{address}`")]
+pub struct InvalidMailAddress {
+    address: String,
+    #[source]
+    source: AddressError,
+}
+
+#[cfg(feature = "lettre")]
+impl<'a> TryFrom<MailAddress<'a>> for Mailbox {
+    type Error = InvalidMailAddress;
+
+    fn try_from(address: MailAddress<'a>) -> Result<Self, Self::Error> {
+        let email = address.email.parse().map_err(|source| InvalidMailAddress {
+            address: address.to_string(),
+            source,
+        })?;
+
+        Ok(Self::new(Some(address.name.into_owned()), email))
+    }
+}
+
+/// Escapes the characters that have a special meaning in html.
+fn escape_html(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// The text of the email, which can contain placeholders like `{name}`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MailBody {
+    text: String,
     #[serde(default)]
-    use_starttls: bool,
+    html: Option<String>,
 }
 
-impl Smtp {
-    #[must_use]
-    pub fn to_transport(&self) -> SmtpTransport {
-        let relay = self.url.as_str();
-        let transport = {
-            if self.use_starttls {
-                SmtpTransport::starttls_relay(relay)
-            } else {
-                SmtpTransport::relay(relay)
-            }
+impl MailBody {
+    pub fn new(text: impl Into<String>, html: Option<String>) -> Self {
+        Self {
+            text: text.into(),
+            html,
         }
-        .unwrap();
+    }
 
-        transport
-            .credentials(Credentials::new(
-                self.username.clone(),
-                self.password.clone(),
-            ))
-            .build()
+    /// Returns the plain text with the placeholders replaced.
+    pub fn text(&self, context: &TemplateContext) -> Result<String, TemplateError> {
+        Template::new(&self.text).render(context)
+    }
+
+    /// Returns the html with the placeholders replaced, the values of the
+    /// placeholders are escaped.
+    pub fn html(&self, context: &TemplateContext) -> Option<Result<String, TemplateError>> {
+        self.html
+            .as_ref()
+            .map(|html| Template::new(html).render_with(context, escape_html))
     }
 }
 
 #[derive(Debug, Clone, Deserialize)]
+// without lettre the config is still parsed, but nothing is sent
+#[cfg_attr(not(feature = "lettre"), allow(dead_code))]
 pub struct Mail {
     from: MailAddress<'static>,
+    /// Who gets the time sheets of contracts without their own recipients.
+    #[serde(default)]
+    recipients: Vec<MailAddress<'static>>,
+    #[serde(default)]
+    cc: Vec<MailAddress<'static>>,
+    #[serde(default)]
+    bcc: Vec<MailAddress<'static>>,
+    #[serde(default)]
+    reply_to: Option<MailAddress<'static>>,
+    #[serde(default)]
+    body: Option<MailBody>,
     smtp: Smtp,
 }
 
 impl Mail {
+    /// Returns a builder with the sender, the copies and where replies should
+    /// go to.
+    #[cfg(feature = "lettre")]
+    pub fn builder(&self) -> anyhow::Result<MessageBuilder> {
+        let mut builder = MessageBuilder::new().from(self.from.clone().try_into()?);
+
+        for address in &self.cc {
+            builder = builder.cc(address.clone().try_into()?);
+        }
+
+        for address in &self.bcc {
+            builder = builder.bcc(address.clone().try_into()?);
+        }
+
+        if let Some(address) = &self.reply_to {
+            builder = builder.reply_to(address.clone().try_into()?);
+        }
+
+        Ok(builder)
+    }
+
+    #[must_use]
+    pub fn recipients(&self) -> &[MailAddress<'static>] {
+        &self.recipients
+    }
+
+    #[must_use]
+    pub fn cc(&self) -> &[MailAddress<'static>] {
+        &self.cc
+    }
+
+    #[must_use]
+    pub fn bcc(&self) -> &[MailAddress<'static>] {
+        &self.bcc
+    }
+
+    #[must_use]
+    pub fn reply_to(&self) -> Option<&MailAddress<'static>> {
+        self.reply_to.as_ref()
+    }
+
     #[must_use]
-    pub fn builder(&self) -> MessageBuilder {
-        MessageBuilder::new().from(self.from.clone().into())
+    pub fn body(&self) -> Option<&MailBody> {
+        self.body.as_ref()
     }
 
-    pub fn to_transport(&self) -> SmtpTransport {
+    #[cfg(feature = "lettre")]
+    pub fn to_transport(&self) -> anyhow::Result<SmtpTransport> {
         self.smtp.to_transport()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_body_placeholders() {
+        let body = MailBody::new(
+            "Hello,\n\nattached is the time sheet of {name} for {month}.",
+            Some("<p>Time sheet of <b>{name}</b></p>".to_string()),
+        );
+        let context = TemplateContext::new()
+            .with("name", "Max <Mustermann>")
+            .with("month", "2022-11");
+
+        assert_eq!(
+            body.text(&context),
+            Ok("Hello,\n\nattached is the time sheet of Max <Mustermann> for 2022-11.".to_string())
+        );
+        assert_eq!(
+            body.html(&context),
+            Some(Ok(
+                "<p>Time sheet of <b>Max &lt;Mustermann&gt;</b></p>".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_mail() {
+        let mail: Mail = toml::from_str(concat!(
+            "from = { name = \"Max Mustermann\", email = \"max@example.com\" }\n",
+            "cc = [{ name = \"Office\", email = \"office@example.com\" }]\n",
+            "reply_to = { name = \"Max\", email = \"reply@example.com\" }\n",
+            "\n",
+            "[body]\n",
+            "text = \"{name}\"\n",
+            "\n",
+            "[smtp]\n",
+            "url = \"smtp.example.com\"\n",
+            "username = \"max\"\n",
+            "password = \"secret\"\n",
+        ))
+        .expect("toml should be valid");
+
+        assert_eq!(mail.cc().len(), 1);
+        assert_eq!(mail.cc()[0].to_string(), "Office <office@example.com>");
+        assert!(mail.bcc().is_empty());
+        assert_eq!(
+            mail.reply_to().map(ToString::to_string),
+            Some("Max <reply@example.com>".to_string())
+        );
+        assert_eq!(mail.body(), Some(&MailBody::new("{name}", None)));
+    }
+
+    #[test]
+    #[cfg(feature = "lettre")]
+    fn test_builder_invalid_address() {
+        let mut mail: Mail = toml::from_str(concat!(
+            "from = { name = \"Max Mustermann\", email = \"max@example.com\" }\n",
+            "\n",
+            "[smtp]\n",
+            "url = \"smtp.example.com\"\n",
+            "username = \"max\"\n",
+            "password = \"secret\"\n",
+        ))
+        .expect("toml should be valid");
+        assert!(mail.builder().is_ok());
+
+        mail.bcc
+            .push(MailAddress::new("Office", "office.example.com"));
+        let error = mail.builder().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "invalid email address in `Office <office.example.com>`"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lettre")]
+    fn test_parse_invalid_address() {
+        let error = toml::from_str::<MailAddress>(concat!(
+            "name = \"Office\"\n",
+            "email = \"office.example.com\"\n",
+        ))
+        .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("invalid email address `office.example.com`"));
+    }
+}
@@ -7,11 +7,16 @@ mod entry_key;
 mod general;
 mod global;
 mod holiday;
+mod latex;
 mod mail;
 mod month;
+mod month_document;
+mod pro_rating;
 mod repeating;
 mod signature;
+mod smtp;
 mod transfer;
+mod vacation;
 
 pub use about::*;
 pub use absence::*;
@@ -22,8 +27,13 @@ pub use entry_key::*;
 pub use general::*;
 pub use global::*;
 pub use holiday::*;
+pub use latex::*;
 pub use mail::*;
 pub use month::*;
+pub use month_document::*;
+pub use pro_rating::*;
 pub use repeating::*;
 pub use signature::*;
+pub use smtp::*;
 pub use transfer::*;
+pub use vacation::*;
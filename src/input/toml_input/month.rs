@@ -51,6 +51,36 @@ impl<'de> MapEntry<'de> for EitherEntry {
     }
 }
 
+/// The entries of a single department in a month file, that contains
+/// multiple departments.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DepartmentSection {
+    #[serde(default)]
+    department: String,
+    transfer: Option<Transfer>,
+    holiday: Option<Holiday>,
+    #[serde(default, deserialize_with = "utils::deserialize_map_entry")]
+    entries: Vec<EitherEntry>,
+    #[serde(default, deserialize_with = "utils::deserialize_map_entry")]
+    dynamic: Vec<DynamicEntry>,
+}
+
+impl DepartmentSection {
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+}
+
+impl<'de> MapEntry<'de> for DepartmentSection {
+    type Key = String;
+    type Value = Self;
+
+    fn new(key: Self::Key, mut value: Self::Value) -> Self {
+        value.department = key;
+        value
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Month {
     general: General,
@@ -62,6 +92,11 @@ pub struct Month {
     dynamic: Vec<DynamicEntry>,
     #[serde(default, deserialize_with = "utils::deserialize_map_entry")]
     absence: Vec<Absence>,
+    #[serde(default, deserialize_with = "utils::deserialize_map_entry")]
+    department: Vec<DepartmentSection>,
+    // whether this month has been created from a `DepartmentSection`
+    #[serde(skip)]
+    is_section: bool,
 }
 
 impl Month {
@@ -73,6 +108,10 @@ impl Month {
         self.transfer
     }
 
+    pub fn set_transfer(&mut self, transfer: Transfer) {
+        self.transfer = Some(transfer);
+    }
+
     pub fn add_entries(&mut self, entries: impl IntoIterator<Item = Entry>) {
         self.entries
             .extend(entries.into_iter().map(EitherEntry::Entry));
@@ -99,4 +138,75 @@ impl Month {
     pub fn holiday(&self) -> Option<&Holiday> {
         self.holiday.as_ref()
     }
+
+    /// Returns whether the month file has sections for multiple departments.
+    #[must_use]
+    pub fn has_department_sections(&self) -> bool {
+        !self.department.is_empty()
+    }
+
+    /// Returns whether this month has been created from a section of a month
+    /// file with multiple departments.
+    #[must_use]
+    pub fn is_section(&self) -> bool {
+        self.is_section
+    }
+
+    fn has_entries(&self) -> bool {
+        self.transfer.is_some()
+            || self.holiday.is_some()
+            || !self.entries.is_empty()
+            || !self.dynamic.is_empty()
+    }
+
+    /// Splits the month file into one month for each department.
+    ///
+    /// The entries outside of the department sections belong to the department
+    /// in the `[general]` section. The absences are shared by all departments.
+    pub fn split_by_department(&self) -> anyhow::Result<Vec<Self>> {
+        if !self.has_department_sections() {
+            return Ok(vec![self.clone()]);
+        }
+
+        let mut result = Vec::with_capacity(self.department.len() + 1);
+
+        if self.general.department().is_empty() {
+            if self.has_entries() {
+                return Err(anyhow::anyhow!(
+                    "entries outside of a department section require a department in `[general]`"
+                ));
+            }
+        } else {
+            result.push(Self {
+                department: Vec::new(),
+                is_section: true,
+                ..self.clone()
+            });
+        }
+
+        for section in &self.department {
+            if result
+                .iter()
+                .any(|month: &Self| month.general.department() == section.department())
+            {
+                return Err(anyhow::anyhow!(
+                    "the department `{}` is specified multiple times",
+                    section.department()
+                ));
+            }
+
+            result.push(Self {
+                general: self.general.with_department(section.department()),
+                transfer: section.transfer,
+                holiday: section.holiday.clone(),
+                entries: section.entries.clone(),
+                dynamic: section.dynamic.clone(),
+                absence: self.absence.clone(),
+                department: Vec::new(),
+                is_section: true,
+            });
+        }
+
+        Ok(result)
+    }
 }
@@ -0,0 +1,310 @@
+use core::fmt;
+use std::str::FromStr;
+
+use anyhow::Context;
+use toml_edit::{value, ArrayOfTables, DocumentMut, Item, Table};
+
+use crate::input::json_input;
+use crate::input::scheduler::SchedulerOptions;
+use crate::input::toml_input::{Entry, Month};
+use crate::input::{self, EntryConflict};
+use crate::time::{self, Date, Year};
+
+/// A month file that can be edited without losing the comments and
+/// the layout of the original file.
+#[derive(Debug, Clone)]
+pub struct MonthDocument {
+    document: DocumentMut,
+}
+
+impl MonthDocument {
+    /// Creates an empty month file for the given month and department.
+    #[must_use]
+    pub fn new(year: Year, month: time::Month, department: &str) -> Self {
+        let mut general = Table::new();
+        general.insert("month", value(month.as_usize() as i64));
+        general.insert("year", value(year.as_usize() as i64));
+        general.insert("department", value(department));
+
+        let mut document = DocumentMut::new();
+        document.insert("general", Item::Table(general));
+
+        Self { document }
+    }
+
+    /// Parses the document as a month, to make sure that it is still valid.
+    pub fn month(&self) -> anyhow::Result<Month> {
+        Ok(toml::from_str(&self.document.to_string())?)
+    }
+
+    /// Checks that the entry does not overlap with other entries or absences
+    /// and that it does not exceed the daily limit.
+    pub fn validate_entry(&self, entry: &Entry) -> anyhow::Result<()> {
+        let month = self.month()?;
+        let date = Date::new(month.general().year(), month.general().month(), entry.day())?;
+
+        let schedule = input::Month::new(
+            month.general().month(),
+            month.general().year(),
+            month.transfer().unwrap_or_default(),
+            month.entries().map(json_input::Entry::from).collect(),
+            Vec::new(),
+            None,
+            month.absences().collect(),
+            SchedulerOptions::default(),
+        );
+
+        schedule
+            .validate_entry(date, &json_input::Entry::from(entry))
+            .map_err(|conflict: EntryConflict| anyhow::anyhow!(conflict))
+    }
+
+    /// Adds the entry to the `[entries]` table of the document.
+    ///
+    /// If there is already an entry on that day, both entries will be
+    /// stored in a list of entries for that day.
+    pub fn add_entry(&mut self, entry: &Entry) -> anyhow::Result<()> {
+        self.validate_entry(entry)?;
+
+        let entries = self
+            .document
+            .entry("entries")
+            .or_insert_with(|| {
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .context("`entries` must be a table")?;
+
+        let key = entry.day().to_string();
+        let Some(day) = entries.get_mut(&key) else {
+            entries.insert(&key, Item::Table(entry_table(entry)));
+            return Ok(());
+        };
+
+        let day = day
+            .as_table_like_mut()
+            .with_context(|| format!("`entries.{}` must be a table", key))?;
+
+        if let Some(list) = day.get_mut("entries") {
+            if let Some(list) = list.as_array_of_tables_mut() {
+                list.push(entry_table(entry));
+            } else if let Some(list) = list.as_array_mut() {
+                list.push(entry_table(entry).into_inline_table());
+            } else {
+                return Err(anyhow::anyhow!("`entries.{}.entries` must be a list", key));
+            }
+
+            return Ok(());
+        }
+
+        // there is a single entry on that day, which has to be converted
+        // into a list of entries:
+        let mut existing = Table::new();
+        for (key, item) in day.iter() {
+            existing.insert(key, item.clone());
+        }
+        day.clear();
+
+        let mut list = ArrayOfTables::new();
+        list.push(existing);
+        list.push(entry_table(entry));
+
+        day.insert("entries", Item::ArrayOfTables(list));
+
+        Ok(())
+    }
+}
+
+fn entry_table(entry: &Entry) -> Table {
+    let mut table = Table::new();
+
+    table.insert("action", value(entry.action()));
+    table.insert("start", value(entry.start().to_string()));
+    table.insert("end", value(entry.end().to_string()));
+
+    if let Some(pause) = entry.pause() {
+        table.insert("pause", value(pause.to_string()));
+    }
+
+    if entry.is_vacation() {
+        table.insert("is_vacation", value(true));
+    }
+
+    table
+}
+
+impl FromStr for MonthDocument {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let result = Self {
+            document: string.parse()?,
+        };
+
+        // ensure that the document is a valid month:
+        result.month()?;
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for MonthDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.document.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::time::TimeSpan;
+    use crate::{time_stamp, working_duration};
+
+    const MONTH: &str = concat!(
+        "# the general section\n",
+        "[general]\n",
+        "month = 12\n",
+        "year = 2022\n",
+        "department = \"IANA\"\n",
+        "\n",
+        "# semester holidays\n",
+        "[absence.24-31]\n",
+        "start = \"00:00\"\n",
+        "end = \"23:59\"\n",
+        "\n",
+        "[entries.13]\n",
+        "action = \"Besprechung\" # weekly meeting\n",
+        "start = \"13:10\"\n",
+        "end = \"14:30\"\n",
+    );
+
+    fn entry(day: usize, action: &str, span: TimeSpan) -> Entry {
+        Entry::new(day, action.to_string(), span, None, None)
+    }
+
+    #[test]
+    fn test_add_entry_on_new_day() {
+        let mut document: MonthDocument = MONTH.parse().unwrap();
+
+        document
+            .add_entry(&Entry::new(
+                15,
+                "Korrektur".to_string(),
+                TimeSpan::new(time_stamp!(12:00), time_stamp!(15:00)),
+                Some(working_duration!(00:15)),
+                None,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            document.to_string(),
+            MONTH.to_string()
+                + concat!(
+                    "\n",
+                    "[entries.15]\n",
+                    "action = \"Korrektur\"\n",
+                    "start = \"12:00\"\n",
+                    "end = \"15:00\"\n",
+                    "pause = \"00:15\"\n",
+                )
+        );
+    }
+
+    #[test]
+    fn test_add_entry_on_existing_day() {
+        let mut document: MonthDocument = MONTH.parse().unwrap();
+
+        document
+            .add_entry(&entry(
+                13,
+                "Korrektur",
+                TimeSpan::new(time_stamp!(15:00), time_stamp!(16:00)),
+            ))
+            .unwrap();
+
+        let month = document.month().unwrap();
+        let actions = month
+            .entries()
+            .map(|entry| (entry.day(), entry.action().to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actions,
+            vec![
+                (13, "Besprechung".to_string()),
+                (13, "Korrektur".to_string())
+            ]
+        );
+        assert!(document.to_string().starts_with("# the general section\n"));
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            MonthDocument::new(Year::new(2022), time::Month::December, "IANA").to_string(),
+            concat!(
+                "[general]\n",
+                "month = 12\n",
+                "year = 2022\n",
+                "department = \"IANA\"\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_add_entry_to_empty_month() {
+        let mut document = MonthDocument::new(Year::new(2022), time::Month::December, "IANA");
+
+        document
+            .add_entry(&entry(
+                1,
+                "Korrektur",
+                TimeSpan::new(time_stamp!(10:00), time_stamp!(11:00)),
+            ))
+            .unwrap();
+
+        assert_eq!(document.month().unwrap().entries().count(), 1);
+        assert!(!document.to_string().contains("[entries]\n"));
+    }
+
+    #[test]
+    fn test_rejects_overlapping_entry() {
+        let mut document: MonthDocument = MONTH.parse().unwrap();
+
+        assert!(document
+            .add_entry(&entry(
+                13,
+                "Korrektur",
+                TimeSpan::new(time_stamp!(14:00), time_stamp!(15:00)),
+            ))
+            .is_err());
+
+        assert!(document
+            .add_entry(&entry(
+                24,
+                "Korrektur",
+                TimeSpan::new(time_stamp!(14:00), time_stamp!(15:00)),
+            ))
+            .is_err());
+
+        assert_eq!(document.to_string(), MONTH);
+    }
+
+    #[test]
+    fn test_rejects_exceeding_daily_limit() {
+        let mut document: MonthDocument = MONTH.parse().unwrap();
+
+        assert!(document
+            .add_entry(&entry(
+                13,
+                "Korrektur",
+                TimeSpan::new(time_stamp!(15:00), time_stamp!(22:30)),
+            ))
+            .is_err());
+    }
+}
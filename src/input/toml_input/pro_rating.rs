@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use derive_more::Display;
+use serde::{de, ser, Deserialize, Serialize};
+
+use crate::time::Date;
+
+/// How the working time of a month is reduced, if the contract does not
+/// cover the whole month.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Display)]
+pub enum ProRating {
+    /// The working time is proportional to the number of workdays covered
+    /// by the contract.
+    #[default]
+    #[display(fmt = "workdays")]
+    Workdays,
+    /// The working time is proportional to the number of days covered by
+    /// the contract.
+    #[display(fmt = "calendar-days")]
+    CalendarDays,
+}
+
+impl ProRating {
+    /// Returns whether the date counts towards the working time.
+    #[must_use]
+    pub const fn counts(&self, date: Date) -> bool {
+        match self {
+            Self::Workdays => date.is_workday(),
+            Self::CalendarDays => true,
+        }
+    }
+}
+
+impl FromStr for ProRating {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "workdays" => Ok(Self::Workdays),
+            "calendar-days" => Ok(Self::CalendarDays),
+            _ => Err(anyhow::anyhow!("Invalid pro rating: {}", string)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProRating {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for ProRating {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
@@ -0,0 +1,340 @@
+use core::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+#[cfg(feature = "lettre")]
+use std::time::Duration;
+use std::{env, fs};
+
+use anyhow::Context;
+#[cfg(feature = "lettre")]
+use lettre::transport::smtp::authentication::{Credentials, Mechanism};
+#[cfg(feature = "lettre")]
+use lettre::transport::smtp::SmtpTransport;
+use serde::Deserialize;
+
+/// How the connection to the smtp server is encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    /// The connection is not encrypted, for example for an internal relay.
+    None,
+    /// The connection is upgraded to tls after connecting (usually port 587).
+    Starttls,
+    /// The connection is encrypted from the start (usually port 465).
+    #[default]
+    Implicit,
+}
+
+/// How to authenticate at the smtp server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpMechanism {
+    Plain,
+    Login,
+    Xoauth2,
+}
+
+#[cfg(feature = "lettre")]
+impl From<SmtpMechanism> for Mechanism {
+    fn from(mechanism: SmtpMechanism) -> Self {
+        match mechanism {
+            SmtpMechanism::Plain => Self::Plain,
+            SmtpMechanism::Login => Self::Login,
+            SmtpMechanism::Xoauth2 => Self::Xoauth2,
+        }
+    }
+}
+
+/// A value that is never shown, for example in the logs.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***")
+    }
+}
+
+/// Reads the password from a file, that must not be accessible by others.
+fn read_password_file(path: &Path) -> anyhow::Result<Secret> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs::metadata(path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?
+            .permissions()
+            .mode();
+
+        if mode & 0o077 != 0 {
+            return Err(anyhow::anyhow!(
+                "`{}` can be accessed by other users (mode {:o}), restrict it with `chmod 600`",
+                path.display(),
+                mode & 0o777
+            ));
+        }
+    }
+
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?;
+
+    Ok(Secret(
+        content.lines().next().unwrap_or_default().to_string(),
+    ))
+}
+
+/// Runs the command and returns the first line it prints as password.
+fn run_password_command(command: &[String]) -> anyhow::Result<Secret> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("the `password_command` is empty"))?;
+
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .with_context(|| format!("failed to run the password command `{}`", program))?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "the password command `{}` failed with {}",
+            program,
+            output.status
+        ));
+    }
+
+    let stdout = String::from_utf8(output.stdout)
+        .map_err(|_| anyhow::anyhow!("the password command `{}` did not print utf-8", program))?;
+
+    Ok(Secret(
+        stdout.lines().next().unwrap_or_default().to_string(),
+    ))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+// without lettre the config is still parsed, but nothing is sent
+#[cfg_attr(not(feature = "lettre"), allow(dead_code))]
+pub struct Smtp {
+    url: String,
+    port: Option<u16>,
+    tls: Option<SmtpTls>,
+    /// Replaced by `tls = "starttls"`, still supported for older configs.
+    #[serde(default)]
+    use_starttls: bool,
+    username: Option<String>,
+    // only one of the following sources for the password can be used
+    password: Option<Secret>,
+    /// The name of the environment variable with the password.
+    password_env: Option<String>,
+    /// The command and its arguments, that prints the password.
+    password_command: Option<Vec<String>>,
+    /// The file with the password, that only the owner can access.
+    password_file: Option<PathBuf>,
+    /// How many seconds to wait for the server.
+    timeout: Option<u64>,
+    /// Which mechanisms can be used to authenticate, by default the ones
+    /// supported by the server.
+    #[serde(default)]
+    mechanisms: Vec<SmtpMechanism>,
+}
+
+impl Smtp {
+    #[must_use]
+    pub fn tls(&self) -> SmtpTls {
+        self.tls.unwrap_or({
+            if self.use_starttls {
+                SmtpTls::Starttls
+            } else {
+                SmtpTls::Implicit
+            }
+        })
+    }
+
+    /// Returns the password from whichever source is configured.
+    ///
+    /// This should only be called when the password is needed, because it
+    /// might run a command or ask a password manager.
+    pub fn password(&self) -> anyhow::Result<Option<Secret>> {
+        let sources = [
+            self.password.is_some(),
+            self.password_env.is_some(),
+            self.password_command.is_some(),
+            self.password_file.is_some(),
+        ];
+
+        if sources.into_iter().filter(|is_set| *is_set).count() > 1 {
+            return Err(anyhow::anyhow!(
+                "only one of `password`, `password_env`, `password_command` and `password_file` can be used"
+            ));
+        }
+
+        if let Some(password) = &self.password {
+            Ok(Some(password.clone()))
+        } else if let Some(variable) = &self.password_env {
+            env::var(variable)
+                .map(|password| Some(Secret(password)))
+                .with_context(|| format!("the environment variable `{}` is not set", variable))
+        } else if let Some(command) = &self.password_command {
+            run_password_command(command).map(Some)
+        } else if let Some(path) = &self.password_file {
+            read_password_file(path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "lettre")]
+    pub fn to_transport(&self) -> anyhow::Result<SmtpTransport> {
+        let relay = self.url.as_str();
+        let mut transport = match self.tls() {
+            SmtpTls::None => SmtpTransport::builder_dangerous(relay),
+            SmtpTls::Starttls => SmtpTransport::starttls_relay(relay)
+                .with_context(|| format!("invalid smtp server `{}`", relay))?,
+            SmtpTls::Implicit => SmtpTransport::relay(relay)
+                .with_context(|| format!("invalid smtp server `{}`", relay))?,
+        };
+
+        if let Some(port) = self.port {
+            transport = transport.port(port);
+        }
+
+        if let Some(timeout) = self.timeout {
+            transport = transport.timeout(Some(Duration::from_secs(timeout)));
+        }
+
+        match (&self.username, self.password()?) {
+            (Some(username), Some(password)) => {
+                transport = transport.credentials(Credentials::new(
+                    username.clone(),
+                    password.expose().to_string(),
+                ));
+            }
+            (None, None) => {}
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "the smtp config needs both a username and a password or neither"
+                ))
+            }
+        }
+
+        if !self.mechanisms.is_empty() {
+            transport = transport.authentication(
+                self.mechanisms
+                    .iter()
+                    .copied()
+                    .map(Mechanism::from)
+                    .collect(),
+            );
+        }
+
+        Ok(transport.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_tls() {
+        let smtp: Smtp = toml::from_str("url = \"smtp.example.com\"\n").unwrap();
+        assert_eq!(smtp.tls(), SmtpTls::Implicit);
+
+        let smtp: Smtp =
+            toml::from_str("url = \"smtp.example.com\"\nuse_starttls = true\n").unwrap();
+        assert_eq!(smtp.tls(), SmtpTls::Starttls);
+
+        let smtp: Smtp = toml::from_str("url = \"localhost\"\ntls = \"none\"\n").unwrap();
+        assert_eq!(smtp.tls(), SmtpTls::None);
+    }
+
+    #[test]
+    fn test_password_is_not_shown() {
+        let smtp: Smtp = toml::from_str(concat!(
+            "url = \"smtp.example.com\"\n",
+            "username = \"max\"\n",
+            "password = \"secret\"\n",
+        ))
+        .unwrap();
+
+        assert!(!format!("{:?}", smtp).contains("secret"));
+        assert_eq!(smtp.password().unwrap().unwrap().expose(), "secret");
+    }
+
+    #[test]
+    fn test_password_env() {
+        env::set_var("TIME_SHEET_TEST_SMTP_PASSWORD", "from env");
+        let smtp: Smtp = toml::from_str(concat!(
+            "url = \"smtp.example.com\"\n",
+            "password_env = \"TIME_SHEET_TEST_SMTP_PASSWORD\"\n",
+        ))
+        .unwrap();
+
+        assert_eq!(smtp.password().unwrap().unwrap().expose(), "from env");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_password_command() {
+        let smtp: Smtp = toml::from_str(concat!(
+            "url = \"smtp.example.com\"\n",
+            "password_command = [\"echo\", \"from command\"]\n",
+        ))
+        .unwrap();
+
+        assert_eq!(smtp.password().unwrap().unwrap().expose(), "from command");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_password_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("password");
+        fs::write(&path, "from file\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let smtp: Smtp = toml::from_str(&format!(
+            "url = \"smtp.example.com\"\npassword_file = {:?}\n",
+            path.display().to_string()
+        ))
+        .unwrap();
+
+        // others can read the file
+        assert!(smtp.password().is_err());
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(smtp.password().unwrap().unwrap().expose(), "from file");
+    }
+
+    #[test]
+    fn test_multiple_password_sources() {
+        let smtp: Smtp = toml::from_str(concat!(
+            "url = \"smtp.example.com\"\n",
+            "password = \"secret\"\n",
+            "password_env = \"SMTP_PASSWORD\"\n",
+        ))
+        .unwrap();
+
+        assert!(smtp.password().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "lettre")]
+    fn test_partial_credentials() {
+        let smtp: Smtp =
+            toml::from_str("url = \"smtp.example.com\"\nusername = \"max\"\n").unwrap();
+
+        assert!(smtp.to_transport().is_err());
+    }
+}
@@ -158,15 +158,20 @@ impl Add<Transfer> for WorkingDuration {
 }
 
 impl fmt::Debug for Transfer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Transfer").field(&self.to_string()).finish()
+    }
+}
+
+impl fmt::Display for Transfer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (sign, net_transfer) = self.net_transfer();
-        f.debug_tuple("Transfer")
-            .field(&format!(
-                "{}{}",
-                sign.symbol(),
-                WorkingDuration::from(net_transfer)
-            ))
-            .finish()
+        write!(
+            f,
+            "{}{}",
+            sign.symbol(),
+            WorkingDuration::from(net_transfer)
+        )
     }
 }
 
@@ -233,4 +238,14 @@ mod tests {
             Transfer::new(working_duration!(00:00), working_duration!(00:00))
         );
     }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(transfer!(+01:30).to_string(), "+01:30");
+        assert_eq!(transfer!(-00:45).to_string(), "-00:45");
+        assert_eq!(
+            Transfer::new(working_duration!(02:00), working_duration!(00:30)).to_string(),
+            "-01:30"
+        );
+    }
 }
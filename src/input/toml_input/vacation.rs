@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+const fn default_days_per_year() -> usize {
+    20
+}
+
+const fn default_hours_per_day() -> f32 {
+    3.95
+}
+
+const fn default_maximum_hours_per_month() -> f32 {
+    85.0
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InvalidVacation {
+    #[error("`hours_per_day` has to be zero or more, but is {0}")]
+    HoursPerDay(f32),
+    #[error("`maximum_hours_per_month` has to be at least one minute, but is {0}")]
+    MaximumHoursPerMonth(f32),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct RawVacation {
+    #[serde(default = "default_days_per_year")]
+    days_per_year: usize,
+    #[serde(default = "default_hours_per_day")]
+    hours_per_day: f32,
+    #[serde(default = "default_maximum_hours_per_month")]
+    maximum_hours_per_month: f32,
+}
+
+/// The vacation entitlement of a contract.
+///
+/// The vacation time for a month is calculated like this:
+/// `working_time * days_per_year * hours_per_day / (maximum_hours_per_month * 12)`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "RawVacation")]
+pub struct Vacation {
+    /// How many vacation days one has per year with a full-time contract.
+    days_per_year: usize,
+    /// How many hours a vacation day has with a full-time contract.
+    hours_per_day: f32,
+    /// How many hours a full-time contract has per month.
+    maximum_hours_per_month: f32,
+}
+
+impl Vacation {
+    #[must_use]
+    pub const fn new(
+        days_per_year: usize,
+        hours_per_day: f32,
+        maximum_hours_per_month: f32,
+    ) -> Self {
+        Self {
+            days_per_year,
+            hours_per_day,
+            maximum_hours_per_month,
+        }
+    }
+
+    #[must_use]
+    pub const fn days_per_year(&self) -> usize {
+        self.days_per_year
+    }
+
+    #[must_use]
+    pub const fn hours_per_day(&self) -> f32 {
+        self.hours_per_day
+    }
+
+    #[must_use]
+    pub const fn maximum_hours_per_month(&self) -> f32 {
+        self.maximum_hours_per_month
+    }
+}
+
+impl Default for Vacation {
+    fn default() -> Self {
+        Self::new(
+            default_days_per_year(),
+            default_hours_per_day(),
+            default_maximum_hours_per_month(),
+        )
+    }
+}
+
+impl TryFrom<RawVacation> for Vacation {
+    type Error = InvalidVacation;
+
+    fn try_from(raw: RawVacation) -> Result<Self, Self::Error> {
+        if !(raw.hours_per_day.is_finite() && raw.hours_per_day >= 0.0) {
+            return Err(InvalidVacation::HoursPerDay(raw.hours_per_day));
+        }
+
+        // the formula divides by the maximum in minutes
+        if !(raw.maximum_hours_per_month.is_finite()
+            && (raw.maximum_hours_per_month * 60.0).round() >= 1.0)
+        {
+            return Err(InvalidVacation::MaximumHoursPerMonth(
+                raw.maximum_hours_per_month,
+            ));
+        }
+
+        Ok(Self::new(
+            raw.days_per_year,
+            raw.hours_per_day,
+            raw.maximum_hours_per_month,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_vacation() {
+        assert_eq!(
+            toml::from_str::<Vacation>("days_per_year = 30\n").unwrap(),
+            Vacation::new(30, 3.95, 85.0)
+        );
+
+        for (toml, expected) in [
+            (
+                "maximum_hours_per_month = 0\n",
+                InvalidVacation::MaximumHoursPerMonth(0.0).to_string(),
+            ),
+            (
+                "maximum_hours_per_month = nan\n",
+                InvalidVacation::MaximumHoursPerMonth(f32::NAN).to_string(),
+            ),
+            (
+                "hours_per_day = -1.5\n",
+                InvalidVacation::HoursPerDay(-1.5).to_string(),
+            ),
+        ] {
+            let error = toml::from_str::<Vacation>(toml)
+                .expect_err("the vacation should be invalid")
+                .to_string();
+
+            assert!(
+                error.contains(&expected),
+                "{:?} should contain {:?}",
+                error,
+                expected
+            );
+        }
+    }
+}
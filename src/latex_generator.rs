@@ -1,12 +1,15 @@
+use std::ffi::OsStr;
 use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+use anyhow::Context;
 use log::{debug, info};
 use tempfile::TempDir;
 
 use crate::input::Config;
-use crate::tex_render::TexRender;
+use crate::latex_log::{LatexDiagnostic, SPECIAL_CHARACTERS};
+use crate::tex_render::{RenderingError, TexRender};
 use crate::utils::{self, Resources};
 
 #[must_use]
@@ -24,13 +27,87 @@ fn inject_fix(lines: impl Iterator<Item = impl AsRef<str>>) -> String {
     result
 }
 
+/// Writes the embedded jar to `path`.
+pub fn extract_jar(path: impl AsRef<Path>) -> anyhow::Result<()> {
+    let jar =
+        Resources::get("TimeSheetGenerator.jar").expect("jar should be embedded in the binary");
+    utils::write(path, jar.data)?;
+
+    Ok(())
+}
+
 pub struct LatexGenerator<'a> {
     config: &'a Config,
+    jar_path: Option<&'a Path>,
 }
 
 impl<'a> LatexGenerator<'a> {
     pub fn new(config: &'a Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            jar_path: None,
+        }
+    }
+
+    /// Uses an already extracted jar, instead of extracting it again.
+    pub fn with_jar(mut self, jar_path: &'a Path) -> Self {
+        self.jar_path = Some(jar_path);
+        self
+    }
+
+    /// Finds out, which part of the input caused the LaTeX error.
+    fn hint_for(&self, diagnostic: &LatexDiagnostic) -> Option<String> {
+        if let (Some(file), Some(signature)) = (diagnostic.missing_file(), self.config.signature())
+        {
+            if signature.path().file_name() == Some(OsStr::new(file)) {
+                return Some(format!(
+                    "the signature `{}` could not be included, check its path in the global file",
+                    signature.path().display()
+                ));
+            }
+        }
+
+        let month = self.config.month().resolve();
+        let hint = month.entries().find_map(|(_, entry)| {
+            let action = entry.action();
+            let special = action.chars().find(|c| SPECIAL_CHARACTERS.contains(c))?;
+
+            diagnostic
+                .context()
+                .any(|line| line.contains(action))
+                .then(|| {
+                    format!(
+                        "the action \"{}\" on day {} contains `{}`, which has a special meaning in LaTeX and has to be escaped with `\\`",
+                        action,
+                        entry.day(),
+                        special
+                    )
+                })
+        });
+
+        hint
+    }
+
+    /// Adds a hint to LaTeX errors, that explains which input caused them.
+    fn explain(&self, error: anyhow::Error) -> anyhow::Error {
+        match error.downcast::<RenderingError>() {
+            Ok(RenderingError::Latex {
+                program,
+                mut diagnostic,
+            }) => {
+                if let Some(hint) = self.hint_for(&diagnostic) {
+                    diagnostic.set_hint(hint);
+                }
+
+                RenderingError::Latex {
+                    program,
+                    diagnostic,
+                }
+                .into()
+            }
+            Ok(error) => error.into(),
+            Err(error) => error,
+        }
     }
 
     pub fn generate(self, outpath: impl AsRef<Path>) -> anyhow::Result<()> {
@@ -47,12 +124,17 @@ impl<'a> LatexGenerator<'a> {
 
         let month_path = temp_dir.join("month.json");
         let global_path = temp_dir.join("global.json");
-        let jar =
-            Resources::get("TimeSheetGenerator.jar").expect("jar should be embedded in the binary");
-        let jar_path = temp_dir.join("TimeSheetGenerator.jar");
+        let jar_path = {
+            if let Some(path) = self.jar_path {
+                path.to_path_buf()
+            } else {
+                let path = temp_dir.join("TimeSheetGenerator.jar");
+                extract_jar(&path)?;
+                path
+            }
+        };
 
         debug!("temp_dir: {}", temp_dir.display());
-        utils::write(&jar_path, jar.data)?;
         self.config.write_month_json(&month_path)?;
         self.config.write_global_json(&global_path)?;
 
@@ -91,7 +173,7 @@ impl<'a> LatexGenerator<'a> {
                 &format!(
                     "{}\t\\headentry{{\\hspace*{{\\fill}} {date}, \\includegraphics[width={width:.2}cm]{{{signature}}} }} \\par \\medskip\n",
                     prefix,
-                    date = signature.date().formatted("{day}.{month}.{year}"),
+                    date = signature.date().formatted("{day:02}.{month:02}.{year}")?,
                     width = signature.width(),
                     signature = &new_path.to_string_lossy(),
                 ),
@@ -114,9 +196,7 @@ impl<'a> LatexGenerator<'a> {
             Resources::get(logo_file).unwrap().data.as_ref(),
         )?;
 
-        if let Some(path) = self.config.latex_mk_path() {
-            renderer.latex_mk_path(path);
-        }
+        renderer.options(self.config.latex_options());
 
         // add the signature image, if it is present
         if let Some(signature) = self.config.signature() {
@@ -124,7 +204,12 @@ impl<'a> LatexGenerator<'a> {
             renderer.add_asset_from_bytes(
                 //
                 new_path,
-                &fs::read(signature.path())?,
+                &fs::read(signature.path()).with_context(|| {
+                    format!(
+                        "failed to read the signature `{}`",
+                        signature.path().display()
+                    )
+                })?,
             )?;
         }
 
@@ -132,7 +217,8 @@ impl<'a> LatexGenerator<'a> {
             renderer.preserve_dir(dir);
         }
 
-        utils::write(outpath, renderer.render()?)?;
+        let pdf = renderer.render().map_err(|error| self.explain(error))?;
+        utils::write(outpath, pdf)?;
 
         info!("Done");
 
@@ -0,0 +1,278 @@
+//! Extracts the first error from the log of a LaTeX run, so that it can be
+//! shown instead of the whole output of latexmk.
+
+use core::fmt;
+use std::path::Path;
+
+use crate::utils;
+
+/// How many lines of the log after the error are kept.
+const MAX_DETAILS: usize = 4;
+/// How many lines of the source before and after the error are shown.
+const SOURCE_CONTEXT: usize = 2;
+
+/// The characters that have a special meaning in LaTeX and cause errors, if
+/// they are not escaped.
+pub const SPECIAL_CHARACTERS: [char; 7] = ['&', '%', '#', '_', '$', '^', '\\'];
+
+/// Splits a line like `./input.tex:12: Undefined control sequence.` (the
+/// format of `-file-line-error`) into file, line and message.
+fn parse_file_line_error(line: &str) -> Option<(&str, usize, &str)> {
+    line.match_indices(':').find_map(|(index, _)| {
+        let rest = &line[index + 1..];
+        let (number, message) = rest.split_once(": ")?;
+
+        if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        Some((&line[..index], number.parse().ok()?, message))
+    })
+}
+
+/// The line number in a line like `l.12 \foo`, with which TeX shows where
+/// the error occurred.
+fn parse_line_marker(line: &str) -> Option<usize> {
+    let rest = line.strip_prefix("l.")?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+
+    rest[..end].parse().ok()
+}
+
+/// The first error in the log of a LaTeX run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatexDiagnostic {
+    file: Option<String>,
+    line: Option<usize>,
+    message: String,
+    /// The lines of the log after the error, which show where in the line
+    /// the error occurred.
+    details: Vec<String>,
+    /// The lines of the source around the error with their line number.
+    source: Vec<(usize, String)>,
+    /// Explains what in the input caused the error.
+    hint: Option<String>,
+}
+
+impl LatexDiagnostic {
+    /// Returns the first error in the log or `None` if it has none.
+    #[must_use]
+    pub fn from_log(log: &str) -> Option<Self> {
+        let lines = log.lines().collect::<Vec<_>>();
+
+        let (index, file, line, message) = lines.iter().enumerate().find_map(|(index, line)| {
+            if let Some((file, number, message)) = parse_file_line_error(line) {
+                Some((index, Some(file.to_string()), Some(number), message))
+            } else {
+                line.strip_prefix("! ")
+                    .map(|message| (index, None, None, message))
+            }
+        })?;
+
+        let details = lines[index + 1..]
+            .iter()
+            .take_while(|line| !line.trim().is_empty())
+            .take(MAX_DETAILS)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let line = line.or_else(|| details.iter().find_map(|line| parse_line_marker(line)));
+
+        let mut diagnostic = Self {
+            file,
+            line,
+            message: message.trim().to_string(),
+            details,
+            source: Vec::new(),
+            hint: None,
+        };
+        diagnostic.hint = diagnostic.default_hint();
+
+        Some(diagnostic)
+    }
+
+    /// Reads the log file and the source around the error, the file of the
+    /// error is relative to `dir`.
+    pub fn from_log_file(path: impl AsRef<Path>, dir: impl AsRef<Path>) -> Option<Self> {
+        let mut diagnostic = Self::from_log(&utils::read_to_string(path).ok()?)?;
+
+        if let (Some(file), Some(line)) = (&diagnostic.file, diagnostic.line) {
+            if let Ok(source) = utils::read_to_string(dir.as_ref().join(file)) {
+                diagnostic.source = source
+                    .lines()
+                    .enumerate()
+                    .map(|(index, text)| (index + 1, text.to_string()))
+                    .skip(line.saturating_sub(SOURCE_CONTEXT + 1))
+                    .take(2 * SOURCE_CONTEXT + 1)
+                    .collect();
+            }
+        }
+
+        Some(diagnostic)
+    }
+
+    fn default_hint(&self) -> Option<String> {
+        if let Some(file) = self.missing_file() {
+            return Some(format!(
+                "`{}` is included in the time sheet, but does not exist",
+                file
+            ));
+        }
+
+        [
+            "Undefined control sequence",
+            "Misplaced alignment tab character",
+            "Missing $ inserted",
+            "You can't use `macro parameter character #'",
+            "Double subscript",
+            "Double superscript",
+        ]
+        .iter()
+        .any(|message| self.message.starts_with(message))
+        .then(|| {
+            format!(
+                "a text on the time sheet might contain one of {}, which have to be escaped with `\\`",
+                SPECIAL_CHARACTERS
+                    .iter()
+                    .map(|c| format!("`{}`", c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+    }
+
+    /// The name of the file that could not be found, if that is the error.
+    #[must_use]
+    pub fn missing_file(&self) -> Option<&str> {
+        let rest = self.message.split_once("File `")?.1;
+        let (file, rest) = rest.split_once('\'')?;
+
+        rest.trim_start().starts_with("not found").then_some(file)
+    }
+
+    /// The lines of the log and of the source that show where the error
+    /// occurred.
+    pub fn context(&self) -> impl Iterator<Item = &str> + '_ {
+        self.details
+            .iter()
+            .map(String::as_str)
+            .chain(self.source.iter().map(|(_, text)| text.as_str()))
+    }
+
+    pub fn set_hint(&mut self, hint: impl Into<String>) {
+        self.hint = Some(hint.into());
+    }
+}
+
+impl fmt::Display for LatexDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: ", file, line)?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+        writeln!(f, "{}", self.message)?;
+
+        for line in &self.details {
+            writeln!(f, "    {}", line)?;
+        }
+
+        if !self.source.is_empty() {
+            writeln!(f)?;
+            for (number, text) in &self.source {
+                let marker = if Some(*number) == self.line { '>' } else { ' ' };
+                writeln!(f, "{} {:>4} | {}", marker, number, text)?;
+            }
+        }
+
+        if let Some(hint) = &self.hint {
+            write!(f, "\nhint: {}", hint)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const LOG: &str = concat!(
+        "This is XeTeX, Version 3.141592653-2.6-0.999995 (TeX Live 2023)\n",
+        "(./input.tex\n",
+        "LaTeX2e <2022-11-01> patch level 1\n",
+        "./input.tex:3: Misplaced alignment tab character &.\n",
+        "l.3 \\entry{Tutorium &\n",
+        "                      Korrektur}\n",
+        "\n",
+        "./input.tex:7: Undefined control sequence.\n",
+    );
+
+    #[test]
+    fn test_first_error() {
+        let diagnostic = LatexDiagnostic::from_log(LOG).expect("log should contain an error");
+
+        assert_eq!(diagnostic.message, "Misplaced alignment tab character &.");
+        assert_eq!(diagnostic.line, Some(3));
+        assert_eq!(
+            diagnostic.context().collect::<Vec<_>>(),
+            vec!["l.3 \\entry{Tutorium &", "                      Korrektur}"]
+        );
+        assert!(diagnostic.hint.is_some());
+    }
+
+    #[test]
+    fn test_error_without_file_line() {
+        let diagnostic = LatexDiagnostic::from_log(concat!(
+            "! LaTeX Error: File `signature.png' not found.\n",
+            "\n",
+            "See the LaTeX manual or LaTeX Companion for explanation.\n",
+        ))
+        .expect("log should contain an error");
+
+        assert_eq!(diagnostic.line, None);
+        assert_eq!(diagnostic.missing_file(), Some("signature.png"));
+        assert_eq!(
+            diagnostic.to_string(),
+            concat!(
+                "LaTeX Error: File `signature.png' not found.\n",
+                "\n",
+                "hint: `signature.png` is included in the time sheet, but does not exist",
+            )
+        );
+    }
+
+    #[test]
+    fn test_source_context() {
+        let dir = tempfile::tempdir().unwrap();
+        utils::write(dir.path().join("input.tex"), "a\nb\nc & d\ne\nf\ng\n").unwrap();
+        utils::write(dir.path().join("input.log"), LOG).unwrap();
+
+        let diagnostic = LatexDiagnostic::from_log_file(dir.path().join("input.log"), dir.path())
+            .expect("log should contain an error");
+
+        assert_eq!(
+            diagnostic.source,
+            vec![
+                (1, "a".to_string()),
+                (2, "b".to_string()),
+                (3, "c & d".to_string()),
+                (4, "e".to_string()),
+                (5, "f".to_string()),
+            ]
+        );
+        assert!(diagnostic.to_string().contains(">    3 | c & d\n"));
+    }
+
+    #[test]
+    fn test_no_error() {
+        assert_eq!(
+            LatexDiagnostic::from_log("(./input.tex)\nOutput written\n"),
+            None
+        );
+    }
+}
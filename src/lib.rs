@@ -9,21 +9,39 @@
 )]
 
 mod latex_generator;
+mod latex_log;
 mod latex_string;
 mod tex_render;
 mod utils;
 
+#[cfg(feature = "lettre")]
+pub mod email;
 pub mod input;
+pub mod report;
+pub mod send_log;
+pub mod session;
+pub mod template;
 pub mod time;
 
 use std::fs;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::thread;
 
 use log::{info, warn};
+use tempfile::TempDir;
 
+use crate::input::toml_input::LatexOptions;
 use crate::input::Config;
-use crate::latex_generator::LatexGenerator;
+use crate::latex_generator::{extract_jar, LatexGenerator};
+use crate::report::YearSummary;
+use crate::tex_render::TexRender;
 
 pub fn generate_time_sheet(config: &Config) -> anyhow::Result<()> {
+    generate_time_sheet_with(config, None)
+}
+
+fn generate_time_sheet_with(config: &Config, jar_path: Option<&Path>) -> anyhow::Result<()> {
     let total_time = config.month().total_working_time();
     info!("worked: {}", total_time);
 
@@ -36,7 +54,10 @@ pub fn generate_time_sheet(config: &Config) -> anyhow::Result<()> {
 
     info!("generating time sheet from month and global files");
 
-    let generator = LatexGenerator::new(config);
+    let mut generator = LatexGenerator::new(config);
+    if let Some(path) = jar_path {
+        generator = generator.with_jar(path);
+    }
 
     let output = config.output();
     if let Some(parent) = output.parent() {
@@ -47,3 +68,65 @@ pub fn generate_time_sheet(config: &Config) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Generates the time sheets of all configs and returns the result for each
+/// of them in the same order.
+///
+/// The jar is only extracted once and the time sheets are generated
+/// concurrently. If a config preserves its files, they are generated one after
+/// another, so that they do not overwrite each other.
+pub fn generate_time_sheets(configs: &[Config]) -> Vec<anyhow::Result<()>> {
+    // if the jar can not be extracted here, each time sheet extracts it on its own
+    let jar_dir = TempDir::new().ok();
+    let jar_path = jar_dir
+        .as_ref()
+        .map(|dir| dir.path().join("TimeSheetGenerator.jar"))
+        .filter(|path| extract_jar(path).is_ok());
+    let jar_path = jar_path.as_deref();
+
+    let parallelism = {
+        if configs.iter().any(|config| config.preserve_dir().is_some()) {
+            1
+        } else {
+            thread::available_parallelism().map_or(1, NonZeroUsize::get)
+        }
+    };
+
+    let mut results = Vec::with_capacity(configs.len());
+    for chunk in configs.chunks(parallelism) {
+        thread::scope(|scope| {
+            let handles = chunk
+                .iter()
+                .map(|config| scope.spawn(move || generate_time_sheet_with(config, jar_path)))
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                results.push(handle.join().unwrap_or_else(|_| {
+                    Err(anyhow::anyhow!("generating the time sheet panicked"))
+                }));
+            }
+        });
+    }
+
+    results
+}
+
+/// Renders the summary of a year to a pdf.
+pub fn generate_year_summary(
+    summary: &YearSummary,
+    output: impl AsRef<Path>,
+    options: &LatexOptions,
+) -> anyhow::Result<()> {
+    info!("generating summary of {}", summary.year());
+
+    let mut renderer = TexRender::from_bytes(summary.to_latex().into_bytes())?;
+    renderer.options(options);
+
+    if let Some(parent) = output.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    utils::write(output, renderer.render()?)?;
+
+    Ok(())
+}
@@ -3,17 +3,30 @@
 use std::env;
 use std::ffi::OsStr;
 use std::fs;
+#[cfg(feature = "lettre")]
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
 use anyhow::Context as _;
-use lettre::message::header::ContentType;
-use lettre::message::{Attachment, SinglePart};
+#[cfg(feature = "lettre")]
 use lettre::Transport;
-use log::{error, info};
+use log::{error, info, warn};
 use seahorse::{App, Command, Context, Flag};
 
-use time_sheet::generate_time_sheet;
-use time_sheet::input::Config;
+#[cfg(feature = "lettre")]
+use time_sheet::email::{build_email, expand_subject, recipients_of};
+use time_sheet::input::toml_input::{self, MonthDocument};
+use time_sheet::input::{month_files_in, Config, ConfigBuilder};
+use time_sheet::report::{
+    Distribution, Earnings, LabourLawCheck, MonthOverview, MonthStatus, OutputFormat, Statistics,
+    Table, VacationLedger, VacationState, YearSummary,
+};
+use time_sheet::send_log::SendLog;
+#[cfg(feature = "lettre")]
+use time_sheet::send_log::{self, SentMail};
+use time_sheet::session::Session;
+use time_sheet::time::{self, MonthRange, TimeSpan, WorkingDuration, Year};
+use time_sheet::{generate_time_sheet, generate_time_sheets, generate_year_summary};
 
 fn set_env_if_absent<K: AsRef<OsStr>, V: AsRef<OsStr>>(var: K, default: impl FnOnce() -> V) {
     if env::var(var.as_ref()).is_err() {
@@ -106,16 +119,18 @@ mod seahorse_exts {
 
 use seahorse_exts::{ContextExt, TryActionExt};
 
-fn build_config(global: &Path, month: &Path, output: &Path) -> anyhow::Result<Config> {
-    let mut config = Config::try_from_toml_files(month, global)?;
+fn build_configs(global: &Path, month: &Path, output: &Path) -> anyhow::Result<Vec<Config>> {
+    let mut builders = Config::try_all_from_toml_files(month, global)?;
 
-    config.output(output);
+    for builder in &mut builders {
+        builder.output(output);
+    }
 
-    let config = config.build();
+    let configs = ConfigBuilder::build_all(builders)?;
 
-    info!("finished building config");
+    info!("finished building {} config(s)", configs.len());
 
-    Ok(config)
+    Ok(configs)
 }
 
 fn make_extract_context_flags(context: &Context) -> anyhow::Result<(PathBuf, PathBuf, PathBuf)> {
@@ -136,63 +151,100 @@ fn make_extract_context_flags(context: &Context) -> anyhow::Result<(PathBuf, Pat
     Ok((global, month, output))
 }
 
-fn attachment_from_file(path: impl AsRef<Path>) -> anyhow::Result<SinglePart> {
-    let path = path.as_ref();
-
-    Ok(Attachment::new(
-        path.file_name()
-            .ok_or_else(|| anyhow::anyhow!("missing file_name in path \"{}\"", path.display()))?
-            .to_str()
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "failed to convert path to a unicode string: \"{}\"",
-                    path.display()
-                )
-            })?
-            .to_string(),
-    )
-    .body(fs::read(path)?, ContentType::parse("application/pdf")?))
+/// Where the email of a dry run is written to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(feature = "lettre"), allow(dead_code))]
+enum DryRun {
+    Stdout,
+    File(PathBuf),
 }
 
-fn send(config: &Config, recipient: &str, subject: &str, keep_pdf: bool) -> anyhow::Result<()> {
+#[cfg(feature = "lettre")]
+fn send(
+    config: &Config,
+    recipient: Option<&str>,
+    subject: &str,
+    keep_pdf: bool,
+    dry_run: Option<DryRun>,
+    log_path: &Path,
+    force: bool,
+) -> anyhow::Result<()> {
     let mail = config
         .mail()
         .ok_or_else(|| anyhow::anyhow!("missing mail config in global config"))?;
 
-    // adjust subject:
-    let subject = subject
-        .replace("{year:04}", &format!("{:04}", config.month().year()))
-        .replace(
-            "{year:02}",
-            &format!("{:02}", config.month().year().as_usize() % 100),
-        )
-        .replace(
-            "{month:02}",
-            &format!("{:02}", config.month().month().as_usize()),
-        );
+    // an invalid subject should be reported before the time sheet is made
+    let subject_line = expand_subject(subject, config)?;
 
-    make(&config)?;
+    make(config)?;
 
-    let email = mail
-        .builder()
-        .to(recipient.parse()?)
-        .subject(&subject)
-        // attach the file to the email:
-        .singlepart(attachment_from_file(config.output())?)?;
+    let input_hash = send_log::hash_input(config)?;
+    let mut log = SendLog::load(log_path)
+        .with_context(|| format!("failed to read `{}`", log_path.display()))?;
 
-    info!(
-        "sending email to \"{}\" with subject \"{}\"",
-        recipient, &subject
-    );
+    if let Some(sent) = log.find(config) {
+        let change = {
+            if sent.input_hash() == input_hash {
+                "the time sheet is identical"
+            } else {
+                "the time sheet has changed since then"
+            }
+        };
 
-    mail.to_transport().send(&email).with_context(|| {
-        format!(
-            "failed to send email to \"{}\" with subject \"{}\"",
-            recipient, subject
-        )
-    })?;
+        if force || dry_run.is_some() {
+            warn!("{}, {}", sent, change);
+        } else {
+            if !keep_pdf {
+                fs::remove_file(config.output())?;
+            }
+
+            return Err(anyhow::anyhow!(
+                "{}, {}. Use `--force` to send it again",
+                sent,
+                change
+            ));
+        }
+    }
+
+    let email = build_email(config, recipient, subject)?;
+    let subject = subject_line;
+    // the envelope would include the addresses in BCC
+    let recipients = recipients_of(&email);
+
+    match dry_run {
+        Some(DryRun::Stdout) => {
+            io::stdout().write_all(&email.formatted())?;
+        }
+        Some(DryRun::File(path)) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&path, email.formatted())?;
+            info!("wrote email to `{}`", path.display());
+        }
+        None => {
+            info!(
+                "sending email to \"{}\" with subject \"{}\"",
+                recipients.join(", "),
+                &subject
+            );
+
+            mail.to_transport()?.send(&email).with_context(|| {
+                format!(
+                    "failed to send email to \"{}\" with subject \"{}\"",
+                    recipients.join(", "),
+                    subject
+                )
+            })?;
 
-    info!("sent email successfully");
+            info!("sent email successfully");
+
+            log.record(SentMail::new(config, recipients, time::now(), input_hash));
+            log.save(log_path)
+                .with_context(|| format!("failed to write `{}`", log_path.display()))?;
+        }
+    }
 
     if !keep_pdf {
         info!("removing pdf file");
@@ -202,12 +254,485 @@ fn send(config: &Config, recipient: &str, subject: &str, keep_pdf: bool) -> anyh
     Ok(())
 }
 
+#[cfg(not(feature = "lettre"))]
+fn send(
+    _config: &Config,
+    _recipient: Option<&str>,
+    _subject: &str,
+    _keep_pdf: bool,
+    _dry_run: Option<DryRun>,
+    _log_path: &Path,
+    _force: bool,
+) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "mail support was not compiled in, build time-sheet with the `lettre` feature"
+    ))
+}
+
 fn make(config: &Config) -> anyhow::Result<()> {
     generate_time_sheet(config)?;
 
     Ok(())
 }
 
+/// Makes the time sheets of multiple month files in one go.
+///
+/// The months are built in chronological order, so that the transfer of a
+/// month can be taken over by the next month, then all time sheets are
+/// generated concurrently.
+fn make_batch(global: &Path, month_files: &[PathBuf], output: &Path) -> anyhow::Result<()> {
+    let mut results: Vec<(String, anyhow::Result<()>)> = Vec::new();
+    let mut months = Vec::new();
+
+    for path in month_files {
+        let builders = {
+            if path.exists() {
+                Config::try_all_from_toml_files(path, global)
+            } else {
+                Err(anyhow::anyhow!("the month file does not exist"))
+            }
+        };
+
+        match builders {
+            Ok(builders) if !builders.is_empty() => months.push((path, builders)),
+            Ok(_) => results.push((
+                path.display().to_string(),
+                Err(anyhow::anyhow!("the month file has no department")),
+            )),
+            Err(error) => results.push((path.display().to_string(), Err(error))),
+        }
+    }
+
+    months.sort_by_key(|(_, builders)| {
+        let month = builders[0].month();
+        (month.general().year(), month.general().month())
+    });
+
+    // the output has to exist, otherwise it is not recognized as a directory
+    fs::create_dir_all(output)?;
+
+    let mut configs: Vec<Config> = Vec::new();
+    for (path, mut builders) in months {
+        for builder in &mut builders {
+            builder.output(output);
+
+            if let Some(previous) = configs
+                .iter()
+                .rev()
+                .find(|config| config.department() == builder.department())
+            {
+                builder.chain_transfer(previous);
+            }
+        }
+
+        match ConfigBuilder::build_all(builders) {
+            Ok(built) => configs.extend(built),
+            Err(error) => results.push((path.display().to_string(), Err(error))),
+        }
+    }
+
+    info!("generating {} time sheet(s)", configs.len());
+
+    for (config, result) in configs.iter().zip(generate_time_sheets(&configs)) {
+        results.push((
+            format!(
+                "{:04}-{:02} {}",
+                config.month().year(),
+                config.month().month(),
+                config.department()
+            ),
+            result,
+        ));
+    }
+
+    let mut table = Table::new(["Month", "Result"]);
+    let mut failed = 0;
+    for (name, result) in results {
+        if let Err(error) = result {
+            failed += 1;
+            table.push_row([name, format!("failed: {:#}", error)]);
+        } else {
+            table.push_row([name, "ok".to_string()]);
+        }
+    }
+
+    print!("{}", table);
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} time sheet(s) failed", failed));
+    }
+
+    Ok(())
+}
+
+fn show(global: &Path, month: &Path) -> anyhow::Result<()> {
+    let configs = ConfigBuilder::build_all(Config::try_all_from_toml_files(month, global)?)?;
+
+    for (i, config) in configs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        print!("{}", MonthOverview::new(config));
+    }
+
+    Ok(())
+}
+
+fn status(global: &Path, month: &Path, distribution: Distribution) -> anyhow::Result<()> {
+    let configs = ConfigBuilder::build_all(Config::try_all_from_toml_files(month, global)?)?;
+
+    for (i, config) in configs.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        print!("{}", MonthStatus::new(config, time::today(), distribution));
+    }
+
+    Ok(())
+}
+
+fn stats(global: &Path, months: &[PathBuf], format: OutputFormat) -> anyhow::Result<()> {
+    let mut configs = Vec::new();
+    for month in months {
+        configs.extend(ConfigBuilder::build_all(Config::try_all_from_toml_files(
+            month, global,
+        )?)?);
+    }
+
+    let statistics = Statistics::from_configs(&configs);
+
+    match format {
+        OutputFormat::Table => print!("{}", statistics),
+        OutputFormat::Json => println!("{}", statistics.to_json()?),
+    }
+
+    Ok(())
+}
+
+fn check(global: &Path, months: &[PathBuf]) -> anyhow::Result<()> {
+    let mut configs = Vec::new();
+    for month in months {
+        configs.extend(ConfigBuilder::build_all(Config::try_all_from_toml_files(
+            month, global,
+        )?)?);
+    }
+
+    let global: toml_input::Global = toml::from_str(&fs::read_to_string(global)?)
+        .with_context(|| format!("failed to parse `{}`", global.display()))?;
+
+    let mut months = configs
+        .iter()
+        .map(|config| (config.month().year(), config.month().month()))
+        .collect::<Vec<_>>();
+    months.sort();
+    months.dedup();
+
+    // the limits apply to all jobs, so every contract should have a month file
+    for (year, month) in months {
+        for contract in global.contracts() {
+            let has_month_file = configs.iter().any(|config| {
+                config.department() == contract.department()
+                    && config.month().year() == year
+                    && config.month().month() == month
+            });
+
+            if contract.is_active_in(year, month) && !has_month_file {
+                warn!(
+                    "missing month file for `{}` in {:04}-{:02}",
+                    contract.department(),
+                    year,
+                    month
+                );
+            }
+        }
+    }
+
+    let check = LabourLawCheck::new(&configs);
+    print!("{}", check);
+
+    if !check.is_ok() {
+        return Err(anyhow::anyhow!(
+            "found {} violation(s) of the working time law",
+            check.violations().len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn earnings(global: &Path, months: &[PathBuf]) -> anyhow::Result<()> {
+    let mut configs = Vec::new();
+    for month in months {
+        configs.extend(ConfigBuilder::build_all(Config::try_all_from_toml_files(
+            month, global,
+        )?)?);
+    }
+
+    let earnings = Earnings::from_configs(&configs);
+
+    for warning in earnings.warnings() {
+        warn!("{}", warning);
+    }
+
+    print!("{}", earnings);
+
+    Ok(())
+}
+
+fn summary(
+    global: &Path,
+    year: Year,
+    directory: &Path,
+    output: &Path,
+    with_pdf: bool,
+) -> anyhow::Result<()> {
+    let month_files = month_files_in(directory, global)?;
+
+    let global: toml_input::Global = toml::from_str(&fs::read_to_string(global)?)
+        .with_context(|| format!("failed to parse `{}`", global.display()))?;
+
+    let mut configs = Vec::new();
+    for (path, month) in month_files {
+        // the months of other years might be outside of the contract
+        if month.general().year() != year {
+            continue;
+        }
+
+        let builders = Config::try_all_from_toml(month, global.clone())
+            .and_then(ConfigBuilder::build_all)
+            .with_context(|| format!("failed to build `{}`", path.display()))?;
+        configs.extend(builders);
+    }
+
+    let summary = YearSummary::from_configs(year, &configs);
+    if summary.rows().is_empty() {
+        return Err(anyhow::anyhow!(
+            "no month files for {} in `{}`",
+            year,
+            directory.display()
+        ));
+    }
+
+    print!("{}", summary);
+
+    fs::create_dir_all(output)?;
+    let csv_path = output.join(format!("summary-{}.csv", year));
+    fs::write(&csv_path, summary.to_csv())?;
+    info!("wrote `{}`", csv_path.display());
+
+    if with_pdf {
+        let pdf_path = output.join(format!("summary-{}.pdf", year));
+        // all configs share the same global file
+        let options = configs
+            .first()
+            .map(|config| config.latex_options().clone())
+            .unwrap_or_default();
+
+        generate_year_summary(&summary, &pdf_path, &options)?;
+        info!("wrote `{}`", pdf_path.display());
+    }
+
+    Ok(())
+}
+
+fn vacation(global: &Path, year: Year, months: &[PathBuf]) -> anyhow::Result<()> {
+    let global: toml_input::Global = toml::from_str(&fs::read_to_string(global)?)
+        .with_context(|| format!("failed to parse `{}`", global.display()))?;
+
+    let months = months
+        .iter()
+        .map(|month| {
+            toml::from_str::<toml_input::Month>(&fs::read_to_string(month)?)
+                .with_context(|| format!("failed to parse `{}`", month.display()))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let ledger = VacationLedger::new(&global, year, &months)?;
+
+    for account in ledger.accounts() {
+        match account.state() {
+            VacationState::OnTrack => {}
+            VacationState::Under(months) => warn!(
+                "`{}` has taken {} month(s) of vacation less than months have passed",
+                account.department(),
+                months
+            ),
+            VacationState::Over(months) => warn!(
+                "`{}` has taken {} month(s) of vacation more than it is entitled to",
+                account.department(),
+                months
+            ),
+        }
+    }
+
+    print!("{}", ledger);
+
+    Ok(())
+}
+
+fn history(dir: &Path) -> anyhow::Result<()> {
+    let path = dir.join(SendLog::FILE_NAME);
+    let log =
+        SendLog::load(&path).with_context(|| format!("failed to read `{}`", path.display()))?;
+
+    if log.entries().is_empty() {
+        info!("no time sheets have been sent from `{}`", dir.display());
+        return Ok(());
+    }
+
+    let mut table = Table::new([
+        "Month",
+        "Department",
+        "Contract",
+        "Recipients",
+        "Sent",
+        "Hash",
+    ]);
+    for sent in log.entries() {
+        table.push_row([
+            format!("{:04}-{:02}", sent.year(), sent.month()),
+            sent.department().to_string(),
+            sent.contract_start().to_string(),
+            sent.recipients().join(", "),
+            format!("{} {}", sent.date(), sent.time()),
+            // the start of the hash is enough to tell the time sheets apart
+            sent.input_hash().chars().take(12).collect(),
+        ]);
+    }
+
+    print!("{}", table);
+
+    Ok(())
+}
+
+fn log(month: &Path, entry: &toml_input::Entry) -> anyhow::Result<()> {
+    let mut document: MonthDocument = fs::read_to_string(month)?
+        .parse()
+        .with_context(|| format!("failed to parse `{}`", month.display()))?;
+
+    document.add_entry(entry)?;
+    fs::write(month, document.to_string())?;
+
+    info!(
+        "added \"{}\" on day {} to `{}`",
+        entry.action(),
+        entry.day(),
+        month.display()
+    );
+
+    Ok(())
+}
+
+/// The file in which the running session is stored.
+const SESSION_FILE: &str = ".time-sheet-session.toml";
+/// How the month files are named by default.
+const DEFAULT_MONTH_FORMAT: &str = "{month:02}.toml";
+
+fn session_dir(context: &Context) -> PathBuf {
+    context
+        .required_path_flag("dir")
+        .ok()
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn start(dir: &Path, action: &str, department: Option<String>) -> anyhow::Result<()> {
+    let path = dir.join(SESSION_FILE);
+
+    if let Some(session) = Session::load(&path)? {
+        return Err(anyhow::anyhow!(
+            "\"{}\" is already running since {} {}, stop it first",
+            session.action(),
+            session.date(),
+            session.start()
+        ));
+    }
+
+    let (date, now) = time::now();
+    Session::new(action, department, date, now).save(&path)?;
+
+    info!("started \"{}\" on {} at {}", action, date, now);
+
+    Ok(())
+}
+
+fn stop(dir: &Path, month_format: &str, rounding: WorkingDuration) -> anyhow::Result<()> {
+    let path = dir.join(SESSION_FILE);
+    let session =
+        Session::load(&path)?.ok_or_else(|| anyhow::anyhow!("there is no running session"))?;
+
+    let (date, now) = time::now();
+
+    let entries = session.stop(date, now, rounding)?;
+    if entries.is_empty() {
+        warn!(
+            "nothing of \"{}\" is left after rounding to {}, the session keeps running",
+            session.action(),
+            rounding
+        );
+        return Ok(());
+    }
+
+    // all entries are validated before any file is written
+    let mut documents: Vec<(PathBuf, MonthDocument)> = Vec::new();
+    for (date, entry) in entries {
+        let month_path = dir.join(
+            date.formatted(month_format)
+                .context("invalid `--month-format`")?,
+        );
+
+        let index = {
+            if let Some(index) = documents.iter().position(|(path, _)| *path == month_path) {
+                index
+            } else {
+                let document = {
+                    if month_path.exists() {
+                        fs::read_to_string(&month_path)?.parse().with_context(|| {
+                            format!("failed to parse `{}`", month_path.display())
+                        })?
+                    } else {
+                        let department = session.department().ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "`{}` does not exist and can not be created without a department, use `start --department`",
+                                month_path.display()
+                            )
+                        })?;
+
+                        info!("creating `{}`", month_path.display());
+                        MonthDocument::new(date.year(), date.month(), department)
+                    }
+                };
+
+                documents.push((month_path, document));
+                documents.len() - 1
+            }
+        };
+
+        documents[index].1.add_entry(&entry)?;
+
+        info!(
+            "\"{}\" on {} from {} to {}",
+            entry.action(),
+            date,
+            entry.start(),
+            entry.end()
+        );
+    }
+
+    for (month_path, document) in documents {
+        if let Some(parent) = month_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(month_path, document.to_string())?;
+    }
+
+    fs::remove_file(path)?;
+
+    Ok(())
+}
+
 fn run() -> anyhow::Result<()> {
     let args: Vec<String> = env::args().collect();
 
@@ -223,17 +748,76 @@ fn run() -> anyhow::Result<()> {
                 "[optional] Path to the output folder. Default: `<path to month>/pdfs/`",
             ),
         )
+        .flag(
+            Flag::new("range", seahorse::FlagType::String).description(
+                "[optional] Makes the time sheets of all months in the range, like `2023-04..2023-09`.",
+            ),
+        )
+        .flag(
+            Flag::new("dir", seahorse::FlagType::String).description(
+                "[optional] The directory with the month files. Without `--range` all month files in it are made.",
+            ),
+        )
+        .flag(Flag::new("month-format", seahorse::FlagType::String).description(
+            "[optional] The name of the month files for `--range`, `{year}` and `{month}` are replaced. Default: `{month:02}.toml`",
+        ))
         .try_action(|context: &Context| {
+            let range = context
+                .required_string_flag("range")
+                .ok()
+                .map(|range| range.parse::<MonthRange>())
+                .transpose()?;
+            let dir = context.required_path_flag("dir").ok();
+
+            if range.is_some() || dir.is_some() {
+                let global = context.required_path_flag("global")?;
+                let dir = dir.unwrap_or_else(|| PathBuf::from("."));
+                let output = context
+                    .required_path_flag("output")
+                    .ok()
+                    .unwrap_or_else(|| dir.join("pdfs/"));
+
+                let month_files: Vec<PathBuf> = {
+                    if let Some(range) = range {
+                        let month_format = context
+                            .required_string_flag("month-format")
+                            .ok()
+                            .unwrap_or_else(|| DEFAULT_MONTH_FORMAT.to_string());
+
+                        range
+                            .months()
+                            .into_iter()
+                            .map(|date| {
+                                date.formatted(&month_format)
+                                    .map(|name| dir.join(name))
+                                    .context("invalid `--month-format`")
+                            })
+                            .collect::<anyhow::Result<_>>()?
+                    } else {
+                        month_files_in(&dir, &global)?
+                            .into_iter()
+                            .map(|(path, _)| path)
+                            .collect()
+                    }
+                };
+
+                return make_batch(&global, &month_files, &output);
+            }
+
             let (global, month, output) = make_extract_context_flags(context)?;
-            let config = build_config(&global, &month, &output)?;
-            make(&config)
+
+            for config in build_configs(&global, &month, &output)? {
+                make(&config)?;
+            }
+
+            Ok::<(), anyhow::Error>(())
         });
 
     let send_command = Command::new("send")
-        .usage(format!("{} send [args] recipient@example.com", args[0]))
+        .usage(format!("{} send [args] [recipient@example.com]", args[0]))
         .description("Makes a time sheet from the given files and sends it to the email.")
         .flag(
-            Flag::new("subject", seahorse::FlagType::String).description("The title of the email. `{year}` and `{month}` will be replaced with the year/month."),
+            Flag::new("subject", seahorse::FlagType::String).description("The title of the email. Placeholders like `{year}`, `{month:02}` or `{name}` will be replaced."),
         )
         .flag(
             Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
@@ -245,22 +829,351 @@ fn run() -> anyhow::Result<()> {
             ),
         )
         .flag(Flag::new("keep-pdf", seahorse::FlagType::Bool).description("[optional] Keeps the pdf file after sending the email. Default: false"))
+        .flag(Flag::new("dry-run", seahorse::FlagType::Bool).description("[optional] Writes the email to an `.eml` file instead of sending it. Default: false"))
+        .flag(Flag::new("eml", seahorse::FlagType::String).description("[optional] Where the email of `--dry-run` is written to, `-` for stdout. Default: the path of the pdf with `.eml`"))
+        .flag(Flag::new("force", seahorse::FlagType::Bool).description("[optional] Sends the time sheet even if it has already been sent. Default: false"))
         .try_action(|context: &Context| {
             let (global, month, output) = make_extract_context_flags(context)?;
-            let config = build_config(&global, &month, &output)?;
+            let configs = build_configs(&global, &month, &output)?;
+
+            // the log is stored next to the month files
+            let log_path = dunce::canonicalize(&month)?
+                .parent()
+                .ok_or_else(|| anyhow::anyhow!("month should have a parent directory"))?
+                .join(SendLog::FILE_NAME);
+            let force = context.bool_flag("force");
 
             let subject = context.required_string_flag("subject")?;
 
-            if context.args.len() != 1 {
-                return Err(anyhow::anyhow!("missing recipient or too many arguments"));
+            if context.args.len() > 1 {
+                return Err(anyhow::anyhow!("too many arguments, expected at most one recipient"));
             }
 
             let keep_pdf = context.bool_flag("keep-pdf");
 
-            let recipient = &context.args[0];
-            info!("recipient: \"{}\"", recipient);
+            // overrides the recipients of the contracts
+            let recipient = context.args.first().map(String::as_str);
+            if let Some(recipient) = recipient {
+                info!("recipient: \"{}\"", recipient);
+            }
+
+            let eml = context.required_path_flag("eml").ok();
+            if configs.len() > 1 && eml.is_some() && eml.as_deref() != Some(Path::new("-")) {
+                return Err(anyhow::anyhow!(
+                    "`--eml` can not be used with multiple contracts, each email is written next to its pdf"
+                ));
+            }
+
+            for config in &configs {
+                let dry_run = {
+                    if context.bool_flag("dry-run") {
+                        match eml.clone() {
+                            Some(path) if path == Path::new("-") => Some(DryRun::Stdout),
+                            Some(path) => Some(DryRun::File(path)),
+                            None => Some(DryRun::File(config.output().with_extension("eml"))),
+                        }
+                    } else {
+                        None
+                    }
+                };
+
+                send(config, recipient, &subject, keep_pdf, dry_run, &log_path, force)?;
+            }
+
+            Ok(())
+        });
+
+    let show_command = Command::new("show")
+        .usage(format!("{} show [args]", args[0]))
+        .description("Prints the entries that will be on the time sheet, without generating it.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .flag(Flag::new("month", seahorse::FlagType::String).description("Path to the month file."))
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+            let month = context.required_path_flag("month")?;
+
+            show(&global, &month)
+        });
+
+    let status_command = Command::new("status")
+        .usage(format!("{} status [args]", args[0]))
+        .description("Shows how much has been worked until today and how much is remaining.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .flag(Flag::new("month", seahorse::FlagType::String).description(
+            "[optional] Path to the month file. Default: `{month:02}.toml` of the current month",
+        ))
+        .flag(Flag::new("distribution", seahorse::FlagType::String).description(
+            "[optional] How the working time is distributed over the month, `linear` or `workdays`. Default: `workdays`",
+        ))
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+            let month = match context.required_path_flag("month") {
+                Ok(month) => month,
+                Err(_) => PathBuf::from(time::today().formatted(DEFAULT_MONTH_FORMAT)?),
+            };
+
+            let distribution = context
+                .required_string_flag("distribution")
+                .ok()
+                .map(|distribution| distribution.parse::<Distribution>())
+                .transpose()?
+                .unwrap_or_default();
+
+            status(&global, &month, distribution)
+        });
+
+    let stats_command = Command::new("stats")
+        .usage(format!("{} stats [args] <month files...>", args[0]))
+        .description("Shows how much time has been spent per action, department and week day.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .flag(Flag::new("format", seahorse::FlagType::String).description(
+            "[optional] How the statistics are printed, `table` or `json`. Default: `table`",
+        ))
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+
+            if context.args.is_empty() {
+                return Err(anyhow::anyhow!("expected at least one month file"));
+            }
+
+            let months = context.args.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+            let format = context
+                .required_string_flag("format")
+                .ok()
+                .map(|format| format.parse::<OutputFormat>())
+                .transpose()?
+                .unwrap_or_default();
+
+            stats(&global, &months, format)
+        });
+
+    let check_command = Command::new("check")
+        .usage(format!("{} check [args] <month files...>", args[0]))
+        .description("Checks the entries of all contracts against the working time law.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+
+            if context.args.is_empty() {
+                return Err(anyhow::anyhow!("expected at least one month file"));
+            }
+
+            let months = context.args.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+            check(&global, &months)
+        });
+
+    let earnings_command = Command::new("earnings")
+        .usage(format!("{} earnings [args] <month files...>", args[0]))
+        .description("Shows how much has been earned per month and per year.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+
+            if context.args.is_empty() {
+                return Err(anyhow::anyhow!("expected at least one month file"));
+            }
+
+            let months = context.args.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+            earnings(&global, &months)
+        });
+
+    let vacation_command = Command::new("vacation")
+        .usage(format!("{} vacation [args] <month files...>", args[0]))
+        .description("Shows how much vacation has been taken in a year and how much is remaining.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .flag(
+            Flag::new("year", seahorse::FlagType::Int)
+                .description("[optional] The year of the vacation. Default: the current year"),
+        )
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+
+            let year = context
+                .int_flag("year")
+                .ok()
+                .map(|year| {
+                    usize::try_from(year)
+                        .map(Year::new)
+                        .with_context(|| format!("invalid year {}", year))
+                })
+                .transpose()?
+                .unwrap_or_else(|| time::today().year());
+
+            let months = context.args.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+            vacation(&global, year, &months)
+        });
+
+    let summary_command = Command::new("summary")
+        .usage(format!("{} summary [args] <directory>", args[0]))
+        .description("Summarizes the month files of a year in a directory as a table, csv and pdf.")
+        .flag(
+            Flag::new("global", seahorse::FlagType::String).description("Path to the global file."),
+        )
+        .flag(
+            Flag::new("year", seahorse::FlagType::Int)
+                .description("[optional] The year to summarize. Default: the current year"),
+        )
+        .flag(
+            Flag::new("output", seahorse::FlagType::String)
+                .description("[optional] Path to the output folder. Default: `<directory>/pdfs/`"),
+        )
+        .flag(
+            Flag::new("no-pdf", seahorse::FlagType::Bool)
+                .description("[optional] Only writes the csv file. Default: false"),
+        )
+        .try_action(|context: &Context| {
+            let global = context.required_path_flag("global")?;
+
+            let year = context
+                .int_flag("year")
+                .ok()
+                .map(|year| {
+                    usize::try_from(year)
+                        .map(Year::new)
+                        .with_context(|| format!("invalid year {}", year))
+                })
+                .transpose()?
+                .unwrap_or_else(|| time::today().year());
+
+            let directory = match context.args.as_slice() {
+                [directory] => PathBuf::from(directory),
+                _ => return Err(anyhow::anyhow!("expected exactly one directory")),
+            };
+
+            let output = context
+                .required_path_flag("output")
+                .ok()
+                .unwrap_or_else(|| directory.join("pdfs/"));
+
+            summary(
+                &global,
+                year,
+                &directory,
+                &output,
+                !context.bool_flag("no-pdf"),
+            )
+        });
+
+    let history_command =
+        Command::new("history")
+            .usage(format!("{} history [args]", args[0]))
+            .description("Lists the time sheets that have been sent.")
+            .flag(Flag::new("dir", seahorse::FlagType::String).description(
+                "[optional] The folder where the month files are stored. Default: `.`",
+            ))
+            .try_action(|context: &Context| {
+                let dir = context
+                    .required_path_flag("dir")
+                    .ok()
+                    .unwrap_or_else(|| PathBuf::from("."));
+
+                history(&dir)
+            });
+
+    let log_command = Command::new("log")
+        .usage(format!(
+            "{} log [args] <day> <action> <start>-<end>",
+            args[0]
+        ))
+        .description("Adds an entry to the month file, keeping its comments and layout.")
+        .flag(Flag::new("month", seahorse::FlagType::String).description("Path to the month file."))
+        .flag(
+            Flag::new("pause", seahorse::FlagType::String)
+                .description("[optional] How long the pause was, for example `0:15`."),
+        )
+        .try_action(|context: &Context| {
+            let month = context.required_path_flag("month")?;
+
+            let [day, action, span] = context.args.as_slice() else {
+                return Err(anyhow::anyhow!(
+                    "expected the day, the action and the time span of the entry"
+                ));
+            };
+
+            let pause = context
+                .required_string_flag("pause")
+                .ok()
+                .map(|pause| pause.parse::<WorkingDuration>())
+                .transpose()?;
+
+            let entry = toml_input::Entry::new(
+                day.parse()
+                    .with_context(|| format!("\"{}\" is not a valid day", day))?,
+                action.to_string(),
+                span.parse::<TimeSpan>()?,
+                pause,
+                None,
+            );
+
+            log(&month, &entry)
+        });
+
+    let start_command =
+        Command::new("start")
+            .usage(format!("{} start [args] <action>", args[0]))
+            .description("Starts a session, which will be added to the month file on `stop`.")
+            .flag(Flag::new("dir", seahorse::FlagType::String).description(
+                "[optional] The folder where the month files are stored. Default: `.`",
+            ))
+            .flag(
+                Flag::new("department", seahorse::FlagType::String).description(
+                    "[optional] The department, required if the month file has to be created.",
+                ),
+            )
+            .try_action(|context: &Context| {
+                let [action] = context.args.as_slice() else {
+                    return Err(anyhow::anyhow!("expected the action of the session"));
+                };
+
+                start(
+                    &session_dir(context),
+                    action,
+                    context.required_string_flag("department").ok(),
+                )
+            });
+
+    let stop_command = Command::new("stop")
+        .usage(format!("{} stop [args]", args[0]))
+        .description("Stops the running session and adds it to the month file.")
+        .flag(Flag::new("dir", seahorse::FlagType::String).description(
+            "[optional] The folder where the month files are stored. Default: `.`",
+        ))
+        .flag(Flag::new("month-format", seahorse::FlagType::String).description(
+            "[optional] The file name of the month files, `{year}` and `{month}` are replaced. Default: `{month:02}.toml`",
+        ))
+        .flag(Flag::new("round", seahorse::FlagType::String).description(
+            "[optional] Start and end are rounded to a multiple of this duration. Default: `00:05`",
+        ))
+        .try_action(|context: &Context| {
+            let month_format = context
+                .required_string_flag("month-format")
+                .ok()
+                .unwrap_or_else(|| DEFAULT_MONTH_FORMAT.to_string());
+
+            let rounding = context
+                .required_string_flag("round")
+                .ok()
+                .map(|rounding| rounding.parse::<WorkingDuration>())
+                .transpose()?
+                .unwrap_or(Session::DEFAULT_ROUNDING);
 
-            send(&config, recipient, &subject, keep_pdf)
+            stop(&session_dir(context), &month_format, rounding)
         });
 
     let app = App::new(env!("CARGO_PKG_NAME"))
@@ -269,7 +1182,18 @@ fn run() -> anyhow::Result<()> {
         .version(env!("CARGO_PKG_VERSION"))
         .usage(format!("{} [args]", args[0]))
         .command(make_command)
-        .command(send_command);
+        .command(send_command)
+        .command(show_command)
+        .command(status_command)
+        .command(stats_command)
+        .command(check_command)
+        .command(earnings_command)
+        .command(vacation_command)
+        .command(summary_command)
+        .command(history_command)
+        .command(log_command)
+        .command(start_command)
+        .command(stop_command);
 
     app.run(args);
 
@@ -0,0 +1,279 @@
+use core::fmt;
+
+use thiserror::Error;
+
+use crate::input::json_input::GlobalFile;
+use crate::input::Config;
+use crate::report::{Table, TotalDuration};
+use crate::time::{Month, Year};
+
+/// The statutory minimum wage per hour (in euros) and from when on it applies.
+const MINIMUM_WAGES: [(usize, usize, f32); 12] = [
+    (2015, 1, 8.50),
+    (2017, 1, 8.84),
+    (2019, 1, 9.19),
+    (2020, 1, 9.35),
+    (2021, 1, 9.50),
+    (2021, 7, 9.60),
+    (2022, 1, 9.82),
+    (2022, 7, 10.45),
+    (2022, 10, 12.00),
+    (2024, 1, 12.41),
+    (2025, 1, 12.82),
+    (2026, 1, 13.90),
+];
+
+/// Returns the statutory minimum wage in the given month or `None` if there
+/// was no minimum wage.
+#[must_use]
+pub fn minimum_wage_in(year: Year, month: Month) -> Option<f32> {
+    MINIMUM_WAGES
+        .iter()
+        .rev()
+        .find(|(start_year, start_month, _)| {
+            (*start_year, *start_month) <= (year.as_usize(), month.as_usize())
+        })
+        .map(|(_, _, wage)| *wage)
+}
+
+/// Something that might be wrong with the wage of a contract.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum WageWarning {
+    #[error(
+        "the wage of `{department}` ({wage:.2}€) in {year}-{month:02} is below the minimum wage of {minimum:.2}€"
+    )]
+    BelowMinimumWage {
+        department: String,
+        year: Year,
+        month: Month,
+        wage: f32,
+        minimum: f32,
+    },
+    #[error("the contract for `{department}` has no wage, the default of {wage:.2}€ is used")]
+    MissingWage { department: String, wage: f32 },
+}
+
+/// How much has been earned in a month for a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthEarnings {
+    year: Year,
+    month: Month,
+    department: String,
+    worked: TotalDuration,
+    previous: TotalDuration,
+    next: TotalDuration,
+    wage: f32,
+    has_wage: bool,
+}
+
+impl MonthEarnings {
+    #[must_use]
+    pub fn new(config: &Config) -> Self {
+        let month = config.month();
+        let transfer = month.transfer();
+
+        Self {
+            year: month.year(),
+            month: month.month(),
+            department: config.department().to_string(),
+            worked: TotalDuration::from(month.resolve().total_working_time()),
+            previous: TotalDuration::from(transfer.previous()),
+            next: TotalDuration::from(transfer.next()),
+            wage: config.wage().unwrap_or(GlobalFile::DEFAULT_WAGE),
+            has_wage: config.wage().is_some(),
+        }
+    }
+
+    #[must_use]
+    pub const fn year(&self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    #[must_use]
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// The time that is paid in this month.
+    ///
+    /// This is the time of the entries, plus the time that has been transferred
+    /// from the previous month, minus the time that is transferred to the next
+    /// month.
+    #[must_use]
+    pub fn paid(&self) -> TotalDuration {
+        TotalDuration::from_mins(
+            (self.worked.as_mins() + self.previous.as_mins()).saturating_sub(self.next.as_mins()),
+        )
+    }
+
+    #[must_use]
+    pub const fn wage(&self) -> f32 {
+        self.wage
+    }
+
+    /// How much has been earned in euros.
+    #[must_use]
+    pub fn earnings(&self) -> f64 {
+        self.paid().as_hours() * f64::from(self.wage)
+    }
+
+    #[must_use]
+    pub fn warnings(&self) -> Vec<WageWarning> {
+        let mut result = Vec::new();
+
+        if !self.has_wage {
+            result.push(WageWarning::MissingWage {
+                department: self.department.clone(),
+                wage: self.wage,
+            });
+        }
+
+        if let Some(minimum) = minimum_wage_in(self.year, self.month) {
+            if self.wage < minimum {
+                result.push(WageWarning::BelowMinimumWage {
+                    department: self.department.clone(),
+                    year: self.year,
+                    month: self.month,
+                    wage: self.wage,
+                    minimum,
+                });
+            }
+        }
+
+        result
+    }
+}
+
+/// The earnings per month and per year of one or more contracts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Earnings {
+    months: Vec<MonthEarnings>,
+}
+
+impl Earnings {
+    #[must_use]
+    pub fn from_configs<'a>(configs: impl IntoIterator<Item = &'a Config>) -> Self {
+        let mut months = configs
+            .into_iter()
+            .map(MonthEarnings::new)
+            .collect::<Vec<_>>();
+
+        months.sort_by(|left, right| {
+            (left.year, left.month, &left.department).cmp(&(
+                right.year,
+                right.month,
+                &right.department,
+            ))
+        });
+
+        Self { months }
+    }
+
+    #[must_use]
+    pub fn months(&self) -> &[MonthEarnings] {
+        &self.months
+    }
+
+    /// Returns the earnings of each year.
+    #[must_use]
+    pub fn years(&self) -> Vec<(Year, TotalDuration, f64)> {
+        let mut result: Vec<(Year, TotalDuration, f64)> = Vec::new();
+
+        for month in &self.months {
+            match result.last_mut() {
+                Some((year, paid, earnings)) if *year == month.year => {
+                    *paid += month.paid();
+                    *earnings += month.earnings();
+                }
+                _ => result.push((month.year, month.paid(), month.earnings())),
+            }
+        }
+
+        result
+    }
+
+    /// Returns the warnings of all months, a missing wage is only reported
+    /// once per contract.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<WageWarning> {
+        let mut result = Vec::new();
+
+        for warning in self.months.iter().flat_map(MonthEarnings::warnings) {
+            if !result.contains(&warning) {
+                result.push(warning);
+            }
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for Earnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new([
+            "Month",
+            "Department",
+            "Worked",
+            "Transfer",
+            "Paid",
+            "Wage",
+            "Earnings",
+        ]);
+
+        for month in &self.months {
+            table.push_row([
+                format!("{:04}-{:02}", month.year, month.month.as_usize()),
+                month.department.clone(),
+                month.worked.to_string(),
+                format!("+{} -{}", month.previous, month.next),
+                month.paid().to_string(),
+                format!("{:.2}", month.wage),
+                format!("{:.2}", month.earnings()),
+            ]);
+        }
+
+        write!(f, "{}", table)?;
+
+        let mut table = Table::new(["Year", "Paid", "Earnings"]);
+        for (year, paid, earnings) in self.years() {
+            table.push_row([
+                year.to_string(),
+                paid.to_string(),
+                format!("{:.2}", earnings),
+            ]);
+        }
+
+        writeln!(f)?;
+        write!(f, "{}", table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_minimum_wage_in() {
+        assert_eq!(minimum_wage_in(Year::new(2014), Month::December), None);
+        assert_eq!(minimum_wage_in(Year::new(2015), Month::January), Some(8.50));
+        assert_eq!(
+            minimum_wage_in(Year::new(2022), Month::September),
+            Some(10.45)
+        );
+        assert_eq!(
+            minimum_wage_in(Year::new(2022), Month::October),
+            Some(12.00)
+        );
+        assert_eq!(
+            minimum_wage_in(Year::new(2030), Month::January),
+            Some(13.90)
+        );
+    }
+}
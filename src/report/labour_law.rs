@@ -0,0 +1,286 @@
+use core::fmt;
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::input::json_input::Entry;
+use crate::input::Config;
+use crate::time::{Date, WorkingDuration};
+use crate::working_duration;
+
+/// A violation of the working time law, that applies to the work of all
+/// contracts together.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LabourLawViolation {
+    #[error("worked {duration} on {date}, which exceeds the maximum of {limit} per day")]
+    DailyLimit {
+        date: Date,
+        duration: WorkingDuration,
+        limit: WorkingDuration,
+    },
+    #[error("only {rest} of rest between {previous} and {date}, at least {required} are required")]
+    RestPeriod {
+        previous: Date,
+        date: Date,
+        rest: WorkingDuration,
+        required: WorkingDuration,
+    },
+    #[error("worked {worked} on {date} with {pause} of pause, at least {required} are required")]
+    Pause {
+        date: Date,
+        worked: WorkingDuration,
+        pause: WorkingDuration,
+        required: WorkingDuration,
+    },
+}
+
+/// Checks the combined entries of all contracts against the working time law.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabourLawCheck {
+    violations: Vec<LabourLawViolation>,
+}
+
+impl LabourLawCheck {
+    /// How much can be worked on a single day for all jobs together.
+    pub const MAXIMUM_PER_DAY: WorkingDuration = working_duration!(10:00);
+    /// How long the rest between two working days must be.
+    pub const MINIMUM_REST: WorkingDuration = working_duration!(11:00);
+    /// Only breaks between entries that are at least this long count as pause.
+    const MINIMUM_BREAK: WorkingDuration = working_duration!(00:15);
+
+    /// Checks the resolved entries of all configs.
+    #[must_use]
+    pub fn new(configs: &[Config]) -> Self {
+        Self::from_entries(configs.iter().flat_map(|config| {
+            let month = config.month();
+
+            month
+                .resolve()
+                .entries()
+                .map(|(_, entry)| {
+                    let date = Date::new(month.year(), month.month(), entry.day())
+                        .expect("entry should be in the month");
+
+                    (date, entry.clone())
+                })
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    #[must_use]
+    pub fn from_entries(entries: impl IntoIterator<Item = (Date, Entry)>) -> Self {
+        let mut days: BTreeMap<Date, Vec<Entry>> = BTreeMap::new();
+        for (date, entry) in entries {
+            days.entry(date).or_default().push(entry);
+        }
+
+        for entries in days.values_mut() {
+            entries.sort_by_key(|entry| entry.time_span().start());
+        }
+
+        let mut violations = Vec::new();
+        let mut previous: Option<(Date, &[Entry])> = None;
+
+        for (date, entries) in &days {
+            let date = *date;
+            let worked = entries
+                .iter()
+                .map(Entry::work_duration)
+                .sum::<WorkingDuration>();
+
+            if worked > Self::MAXIMUM_PER_DAY {
+                violations.push(LabourLawViolation::DailyLimit {
+                    date,
+                    duration: worked,
+                    limit: Self::MAXIMUM_PER_DAY,
+                });
+            }
+
+            let pause = Self::pause_of(entries);
+            let required = Entry::required_pause(worked).unwrap_or_default();
+            if pause < required {
+                violations.push(LabourLawViolation::Pause {
+                    date,
+                    worked,
+                    pause,
+                    required,
+                });
+            }
+
+            if let Some((previous_date, previous_entries)) = previous {
+                if previous_date + 1 == date {
+                    let end = previous_entries
+                        .iter()
+                        .map(|entry| entry.time_span().end().as_mins())
+                        .max()
+                        .unwrap_or_default();
+                    let start = entries[0].time_span().start().as_mins();
+                    let rest = WorkingDuration::from_mins(24 * 60 - end + start);
+
+                    if rest < Self::MINIMUM_REST {
+                        violations.push(LabourLawViolation::RestPeriod {
+                            previous: previous_date,
+                            date,
+                            rest,
+                            required: Self::MINIMUM_REST,
+                        });
+                    }
+                }
+            }
+
+            previous = Some((date, entries.as_slice()));
+        }
+
+        Self { violations }
+    }
+
+    /// The pauses of the entries together with the breaks between them.
+    fn pause_of(entries: &[Entry]) -> WorkingDuration {
+        let breaks = entries
+            .windows(2)
+            .map(|window| {
+                let (end, start) = (window[0].time_span().end(), window[1].time_span().start());
+
+                if start > end {
+                    WorkingDuration::from_mins(start.as_mins() - end.as_mins())
+                } else {
+                    working_duration!(00:00)
+                }
+            })
+            .filter(|duration| *duration >= Self::MINIMUM_BREAK)
+            .sum::<WorkingDuration>();
+
+        entries
+            .iter()
+            .map(Entry::break_duration)
+            .sum::<WorkingDuration>()
+            + breaks
+    }
+
+    #[must_use]
+    pub fn violations(&self) -> &[LabourLawViolation] {
+        &self.violations
+    }
+
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for LabourLawCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return writeln!(f, "no violations found");
+        }
+
+        for violation in &self.violations {
+            writeln!(f, "{}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    use crate::time::{Month, TimeStamp, Year};
+    use crate::{time_stamp, working_duration};
+
+    fn date(day: usize) -> Date {
+        Date::new(Year::new(2022), Month::November, day).unwrap()
+    }
+
+    fn entry(
+        day: usize,
+        start: TimeStamp,
+        end: TimeStamp,
+        pause: Option<WorkingDuration>,
+    ) -> (Date, Entry) {
+        (date(day), Entry::new("work", day, start, end, pause))
+    }
+
+    #[test]
+    fn test_daily_limit() {
+        let check = LabourLawCheck::from_entries([
+            entry(2, time_stamp!(07:00), time_stamp!(13:00), None),
+            entry(2, time_stamp!(13:45), time_stamp!(18:45), None),
+        ]);
+
+        assert_eq!(
+            check.violations(),
+            &[LabourLawViolation::DailyLimit {
+                date: date(2),
+                duration: working_duration!(11:00),
+                limit: working_duration!(10:00),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_combined_pause() {
+        // 7 hours without a break of at least 15 minutes
+        let check = LabourLawCheck::from_entries([
+            entry(2, time_stamp!(08:00), time_stamp!(12:00), None),
+            entry(2, time_stamp!(12:10), time_stamp!(15:10), None),
+        ]);
+
+        assert_eq!(
+            check.violations(),
+            &[LabourLawViolation::Pause {
+                date: date(2),
+                worked: working_duration!(07:00),
+                pause: working_duration!(00:00),
+                required: working_duration!(00:30),
+            }]
+        );
+
+        // the break between the entries counts as pause
+        let check = LabourLawCheck::from_entries([
+            entry(2, time_stamp!(08:00), time_stamp!(12:00), None),
+            entry(2, time_stamp!(12:30), time_stamp!(15:30), None),
+        ]);
+        assert!(check.is_ok());
+
+        // as well as the pause of the entries
+        let check = LabourLawCheck::from_entries([
+            entry(
+                2,
+                time_stamp!(08:00),
+                time_stamp!(12:00),
+                Some(working_duration!(00:15)),
+            ),
+            entry(2, time_stamp!(12:15), time_stamp!(15:45), None),
+        ]);
+        assert!(check.is_ok());
+    }
+
+    #[test]
+    fn test_rest_period() {
+        let check = LabourLawCheck::from_entries([
+            entry(
+                2,
+                time_stamp!(14:00),
+                time_stamp!(22:00),
+                Some(working_duration!(00:30)),
+            ),
+            entry(3, time_stamp!(08:00), time_stamp!(10:00), None),
+            // there is a day in between
+            entry(5, time_stamp!(06:00), time_stamp!(08:00), None),
+        ]);
+
+        assert_eq!(
+            check.violations(),
+            &[LabourLawViolation::RestPeriod {
+                previous: date(2),
+                date: date(3),
+                rest: working_duration!(10:00),
+                required: working_duration!(11:00),
+            }]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Reports that are printed to the terminal.
+
+mod earnings;
+mod labour_law;
+mod month_overview;
+mod statistics;
+mod status;
+mod table;
+mod total_duration;
+mod vacation_ledger;
+mod year_summary;
+
+pub use earnings::*;
+pub use labour_law::*;
+pub use month_overview::*;
+pub use statistics::*;
+pub use status::*;
+pub use table::*;
+pub use total_duration::*;
+pub use vacation_ledger::*;
+pub use year_summary::*;
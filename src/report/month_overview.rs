@@ -0,0 +1,136 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::input::{Config, Month, ResolvedMonth};
+use crate::report::Table;
+use crate::time::{Date, WorkingDuration};
+
+/// A preview of what will be on the time sheet, grouped by week.
+#[derive(Debug, Clone)]
+pub struct MonthOverview<'a> {
+    month: &'a Month,
+    department: &'a str,
+    resolved: ResolvedMonth,
+}
+
+impl<'a> MonthOverview<'a> {
+    #[must_use]
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            month: config.month(),
+            department: config.department(),
+            resolved: config.month().resolve(),
+        }
+    }
+
+    fn worked_on_day(&self, date: Date) -> WorkingDuration {
+        self.resolved
+            .entries_on_day(date)
+            .map(|(_, entry)| entry.work_duration())
+            .sum()
+    }
+
+    fn fmt_week(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        week_number: usize,
+        dates: RangeInclusive<Date>,
+    ) -> fmt::Result {
+        let mut table = Table::new([
+            "Date", "Time", "Pause", "Worked", "Total", "Origin", "Action",
+        ]);
+
+        for date in dates.clone() {
+            let mut rows = Vec::new();
+
+            for (origin, entry) in self.resolved.entries_on_day(date) {
+                let pause = entry.break_duration();
+
+                rows.push([
+                    String::new(),
+                    entry.time_span().to_string(),
+                    if pause == WorkingDuration::default() {
+                        String::new()
+                    } else {
+                        pause.to_string()
+                    },
+                    entry.work_duration().to_string(),
+                    String::new(),
+                    origin.to_string(),
+                    entry.action().to_string(),
+                ]);
+            }
+
+            for absence in self.month.absences_on_day(date) {
+                rows.push([
+                    String::new(),
+                    absence.time_span().to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    "absence".to_string(),
+                    String::new(),
+                ]);
+            }
+
+            // the date and the total of the day are only in the first row
+            if let Some(first) = rows.first_mut() {
+                first[0] = format!("{} {}", date.week_day().abbreviation(), date);
+                first[4] = self.worked_on_day(date).to_string();
+            }
+
+            for row in rows {
+                table.push_row(row);
+            }
+        }
+
+        let worked = dates
+            .clone()
+            .map(|date| self.worked_on_day(date))
+            .sum::<WorkingDuration>();
+
+        writeln!(
+            f,
+            "Week {}: {} - {}, capacity {}, worked {}",
+            week_number,
+            dates.start(),
+            dates.end(),
+            self.month.capacity_of_week(week_number),
+            worked
+        )?;
+
+        if !table.is_empty() {
+            write!(f, "{}", table)?;
+        }
+
+        writeln!(f)
+    }
+}
+
+impl fmt::Display for MonthOverview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:04}-{:02} ({})",
+            self.month.year(),
+            self.month.month().as_usize(),
+            self.department
+        )?;
+        writeln!(f)?;
+
+        for (week_number, dates) in self.month.year().iter_weeks_in(self.month.month()) {
+            self.fmt_week(f, week_number, dates)?;
+        }
+
+        writeln!(f, "expected: {}", self.month.expected_working_duration())?;
+        writeln!(f, "worked: {}", self.resolved.total_working_time())?;
+        // the time sheet prints the transfer of the month file, what the
+        // scheduler could not distribute is only shown here
+        writeln!(f, "transfer on the time sheet: {}", self.month.transfer())?;
+        writeln!(
+            f,
+            "computed balance (not on the time sheet): {}",
+            self.resolved.transfer()
+        )
+    }
+}
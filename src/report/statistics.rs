@@ -0,0 +1,209 @@
+use core::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::input::{Config, EntryOrigin};
+use crate::report::{Table, TotalDuration};
+use crate::time::{Date, WeekDay};
+
+/// How a report should be printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            _ => Err(anyhow::anyhow!("Unknown output format: {}", string)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NamedDuration {
+    name: String,
+    duration: TotalDuration,
+}
+
+impl NamedDuration {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn duration(&self) -> TotalDuration {
+        self.duration
+    }
+}
+
+/// How much has been worked on what, summed up over one or more months.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Statistics {
+    total: TotalDuration,
+    fixed: TotalDuration,
+    dynamic: TotalDuration,
+    actions: Vec<NamedDuration>,
+    departments: Vec<NamedDuration>,
+    week_days: Vec<NamedDuration>,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Statistics {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            total: TotalDuration::default(),
+            fixed: TotalDuration::default(),
+            dynamic: TotalDuration::default(),
+            actions: Vec::new(),
+            departments: Vec::new(),
+            week_days: WeekDay::week_days()
+                .into_iter()
+                .map(|week_day| NamedDuration {
+                    name: week_day.to_string(),
+                    duration: TotalDuration::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn from_configs<'a>(configs: impl IntoIterator<Item = &'a Config>) -> Self {
+        let mut result = Self::new();
+
+        for config in configs {
+            result.add_month(config);
+        }
+
+        result
+    }
+
+    fn add_to(list: &mut Vec<NamedDuration>, name: &str, duration: TotalDuration) {
+        if let Some(existing) = list.iter_mut().find(|existing| existing.name == name) {
+            existing.duration += duration;
+        } else {
+            list.push(NamedDuration {
+                name: name.to_string(),
+                duration,
+            });
+        }
+    }
+
+    /// Adds all entries that will be on the time sheet of the month.
+    pub fn add_month(&mut self, config: &Config) {
+        let month = config.month();
+
+        for (origin, entry) in month.resolve().entries() {
+            let duration = TotalDuration::from(entry.work_duration());
+            let date = Date::new(month.year(), month.month(), entry.day())
+                .expect("the entries should be in the month");
+
+            self.total += duration;
+            if origin == EntryOrigin::Dynamic {
+                self.dynamic += duration;
+            } else {
+                self.fixed += duration;
+            }
+
+            Self::add_to(&mut self.actions, entry.action(), duration);
+            Self::add_to(&mut self.departments, config.department(), duration);
+            self.week_days[date.week_day().as_usize() - 1].duration += duration;
+        }
+
+        // the ones with the most time first:
+        self.actions
+            .sort_by_key(|entry| core::cmp::Reverse(entry.duration));
+        self.departments
+            .sort_by_key(|entry| core::cmp::Reverse(entry.duration));
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> TotalDuration {
+        self.total
+    }
+
+    /// The time of explicit, repeating and holiday entries.
+    #[must_use]
+    pub const fn fixed(&self) -> TotalDuration {
+        self.fixed
+    }
+
+    /// The time of the scheduled dynamic entries.
+    #[must_use]
+    pub const fn dynamic(&self) -> TotalDuration {
+        self.dynamic
+    }
+
+    /// Returns the time per action, sorted by the duration.
+    pub fn actions(&self) -> &[NamedDuration] {
+        &self.actions
+    }
+
+    pub fn departments(&self) -> &[NamedDuration] {
+        &self.departments
+    }
+
+    /// Returns the time per week day, starting with monday.
+    pub fn week_days(&self) -> &[NamedDuration] {
+        &self.week_days
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn fmt_table(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        column: &str,
+        list: &[NamedDuration],
+    ) -> fmt::Result {
+        let mut table = Table::new([column, "Duration", "Share"]);
+        for entry in list {
+            table.push_row([
+                entry.name.clone(),
+                entry.duration.to_string(),
+                format!("{:.1} %", entry.duration.percentage_of(self.total)),
+            ]);
+        }
+
+        writeln!(f)?;
+        write!(f, "{}", table)
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total: {}", self.total)?;
+        writeln!(
+            f,
+            "fixed: {} ({:.1} %)",
+            self.fixed,
+            self.fixed.percentage_of(self.total)
+        )?;
+        writeln!(
+            f,
+            "dynamic: {} ({:.1} %)",
+            self.dynamic,
+            self.dynamic.percentage_of(self.total)
+        )?;
+
+        self.fmt_table(f, "Action", &self.actions)?;
+        self.fmt_table(f, "Department", &self.departments)?;
+        self.fmt_table(f, "Week day", &self.week_days)
+    }
+}
@@ -0,0 +1,261 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::input::scheduler::MonthScheduler;
+use crate::input::{Config, Month, Transfer};
+use crate::report::Table;
+use crate::time::{Date, WorkingDuration};
+use crate::utils;
+
+/// How the expected working time is distributed over the days of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Distribution {
+    /// Every day of the month has the same share.
+    Linear,
+    /// The time is distributed over the workdays, like it is done when
+    /// scheduling the dynamic entries.
+    #[default]
+    Workdays,
+}
+
+impl FromStr for Distribution {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "linear" => Ok(Self::Linear),
+            "workdays" => Ok(Self::Workdays),
+            _ => Err(anyhow::anyhow!("Unknown distribution: {}", string)),
+        }
+    }
+}
+
+/// How much should be worked in one of the remaining weeks of the month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekStatus {
+    week_number: usize,
+    dates: RangeInclusive<Date>,
+    target: WorkingDuration,
+    planned: WorkingDuration,
+}
+
+impl WeekStatus {
+    #[must_use]
+    pub const fn week_number(&self) -> usize {
+        self.week_number
+    }
+
+    /// The days of the week that have not passed yet.
+    #[must_use]
+    pub fn dates(&self) -> RangeInclusive<Date> {
+        self.dates.clone()
+    }
+
+    /// How much should be worked in the week to reach the expected working
+    /// duration at the end of the month.
+    #[must_use]
+    pub const fn target(&self) -> WorkingDuration {
+        self.target
+    }
+
+    /// How much time is already covered by entries in that week (for example
+    /// repeating events).
+    #[must_use]
+    pub const fn planned(&self) -> WorkingDuration {
+        self.planned
+    }
+
+    /// How much time has to be worked in addition to the planned entries.
+    #[must_use]
+    pub const fn open(&self) -> WorkingDuration {
+        self.target.saturating_sub(self.planned)
+    }
+}
+
+/// Compares the time that has been worked until a date with the time that
+/// should have been worked until then.
+#[derive(Debug, Clone)]
+pub struct MonthStatus<'a> {
+    month: &'a Month,
+    department: &'a str,
+    date: Date,
+    expected_per_day: Vec<(Date, WorkingDuration)>,
+}
+
+impl<'a> MonthStatus<'a> {
+    /// Creates the status of the month on the given date.
+    ///
+    /// If the date is not in the month, the first or last day of the month is
+    /// used instead.
+    #[must_use]
+    pub fn new(config: &'a Config, date: Date, distribution: Distribution) -> Self {
+        let month = config.month();
+        let days = month.year().days_in(month.month());
+        let date = date.clamp(*days.start(), *days.end());
+
+        let expected_per_day = match distribution {
+            Distribution::Linear => {
+                let days = days.collect::<Vec<_>>();
+                let mut distribution = vec![1; days.len()];
+                let remainder = utils::divide_proportionally(
+                    month.expected_working_duration().as_mins() as usize,
+                    &mut distribution,
+                );
+
+                if let Some(last) = distribution.last_mut() {
+                    *last += remainder;
+                }
+
+                days.into_iter()
+                    .zip(distribution)
+                    .map(|(date, mins)| (date, WorkingDuration::from_mins(mins as u16)))
+                    .collect()
+            }
+            Distribution::Workdays => MonthScheduler::expected_per_day(month, month.options()),
+        };
+
+        Self {
+            month,
+            department: config.department(),
+            date,
+            expected_per_day,
+        }
+    }
+
+    #[must_use]
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+
+    fn first_day(&self) -> Date {
+        Date::first_day(self.month.year(), self.month.month())
+    }
+
+    /// Returns the time of the explicit, repeating and holiday entries on
+    /// the given days.
+    fn worked_in(&self, dates: &RangeInclusive<Date>) -> WorkingDuration {
+        self.month
+            .fixed_entries()
+            .filter(|(_, entry)| (dates.start().day()..=dates.end().day()).contains(&entry.day()))
+            .map(|(_, entry)| entry.work_duration())
+            .sum()
+    }
+
+    fn expected_in(&self, dates: &RangeInclusive<Date>) -> WorkingDuration {
+        self.expected_per_day
+            .iter()
+            .filter(|(date, _)| dates.contains(date))
+            .map(|(_, duration)| *duration)
+            .sum()
+    }
+
+    /// The time that has been worked until (including) the date.
+    #[must_use]
+    pub fn worked(&self) -> WorkingDuration {
+        self.worked_in(&(self.first_day()..=self.date))
+    }
+
+    /// The time that should have been worked until (including) the date.
+    #[must_use]
+    pub fn expected(&self) -> WorkingDuration {
+        self.expected_in(&(self.first_day()..=self.date))
+    }
+
+    /// Returns a positive transfer if more has been worked than expected and
+    /// a negative one if one is behind.
+    #[must_use]
+    pub fn difference(&self) -> Transfer {
+        Transfer::new(self.expected(), self.worked()).normalized()
+    }
+
+    /// The time that still has to be worked in this month.
+    #[must_use]
+    pub fn remaining(&self) -> WorkingDuration {
+        self.month
+            .expected_working_duration()
+            .saturating_sub(self.worked())
+    }
+
+    /// Distributes the remaining time over the weeks that have not passed yet.
+    #[must_use]
+    pub fn remaining_weeks(&self) -> Vec<WeekStatus> {
+        let mut weeks = self
+            .month
+            .year()
+            .iter_weeks_in(self.month.month())
+            .filter_map(|(week_number, dates)| {
+                let start = dates.clone().find(|date| *date > self.date)?;
+
+                Some(WeekStatus {
+                    week_number,
+                    dates: start..=*dates.end(),
+                    target: WorkingDuration::default(),
+                    planned: WorkingDuration::default(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut distribution = weeks
+            .iter()
+            .map(|week| self.expected_in(&week.dates).as_mins() as usize)
+            .collect::<Vec<_>>();
+
+        // if no more time is expected in the month, there is nothing to distribute
+        if distribution.iter().sum::<usize>() > 0 {
+            let remainder = utils::divide_proportionally(
+                self.remaining().as_mins() as usize,
+                &mut distribution,
+            );
+
+            if let Some(first) = distribution.first_mut() {
+                *first += remainder;
+            }
+
+            for (week, mins) in weeks.iter_mut().zip(distribution) {
+                week.target = WorkingDuration::from_mins(mins as u16);
+            }
+        }
+
+        for week in weeks.iter_mut() {
+            week.planned = self.worked_in(&week.dates);
+        }
+
+        weeks
+    }
+}
+
+impl fmt::Display for MonthStatus<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} ({})", self.date, self.department)?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "worked: {} of {}",
+            self.worked(),
+            self.month.expected_working_duration()
+        )?;
+        writeln!(f, "expected until today: {}", self.expected())?;
+        writeln!(f, "difference: {}", self.difference())?;
+        writeln!(f, "remaining: {}", self.remaining())?;
+
+        let weeks = self.remaining_weeks();
+        if weeks.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = Table::new(["Week", "Dates", "Target", "Planned", "Open"]);
+        for week in weeks {
+            table.push_row([
+                week.week_number().to_string(),
+                format!("{} - {}", week.dates().start(), week.dates().end()),
+                week.target().to_string(),
+                week.planned().to_string(),
+                week.open().to_string(),
+            ]);
+        }
+
+        writeln!(f)?;
+        write!(f, "{}", table)
+    }
+}
@@ -0,0 +1,104 @@
+use core::fmt;
+
+/// A plain text table, where the columns are aligned with spaces.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    #[must_use]
+    pub fn new<T: ToString>(header: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            header: header.into_iter().map(|cell| cell.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push_row<T: ToString>(&mut self, row: impl IntoIterator<Item = T>) {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = Vec::new();
+
+        for row in core::iter::once(&self.header).chain(self.rows.iter()) {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+                if i == widths.len() {
+                    widths.push(width);
+                } else if widths[i] < width {
+                    widths[i] = width;
+                }
+            }
+        }
+
+        widths
+    }
+
+    fn fmt_row(f: &mut fmt::Formatter<'_>, row: &[String], widths: &[usize]) -> fmt::Result {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+
+            line.push_str(&format!("{:<width$}", cell, width = widths[i]));
+        }
+
+        writeln!(f, "{}", line.trim_end())
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.column_widths();
+
+        Self::fmt_row(f, &self.header, &widths)?;
+        Self::fmt_row(
+            f,
+            &widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>(),
+            &widths,
+        )?;
+
+        for row in &self.rows {
+            Self::fmt_row(f, row, &widths)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        let mut table = Table::new(["Day", "Action", "Duration"]);
+        table.push_row(["1", "Korrektur", "01:00"]);
+        table.push_row(["12", "Besprechung", ""]);
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "Day  Action       Duration\n",
+                "---  -----------  --------\n",
+                "1    Korrektur    01:00\n",
+                "12   Besprechung\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+use serde::{ser, Serialize};
+
+use crate::time::WorkingDuration;
+
+/// A sum of working durations, which unlike [`WorkingDuration`] can exceed
+/// 99 hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TotalDuration {
+    minutes: u64,
+}
+
+impl TotalDuration {
+    #[must_use]
+    pub const fn from_mins(minutes: u64) -> Self {
+        Self { minutes }
+    }
+
+    #[must_use]
+    pub const fn as_mins(&self) -> u64 {
+        self.minutes
+    }
+
+    #[must_use]
+    pub fn as_hours(&self) -> f64 {
+        self.minutes as f64 / 60.0
+    }
+
+    /// Returns how many percent of `total` this duration is.
+    #[must_use]
+    pub fn percentage_of(&self, total: Self) -> f64 {
+        if total.minutes == 0 {
+            0.0
+        } else {
+            self.minutes as f64 * 100.0 / total.minutes as f64
+        }
+    }
+}
+
+impl From<WorkingDuration> for TotalDuration {
+    fn from(duration: WorkingDuration) -> Self {
+        Self::from_mins(duration.as_mins() as u64)
+    }
+}
+
+impl fmt::Display for TotalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.minutes / 60, self.minutes % 60)
+    }
+}
+
+impl Serialize for TotalDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl Add for TotalDuration {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::from_mins(self.minutes + other.minutes)
+    }
+}
+
+impl Add<WorkingDuration> for TotalDuration {
+    type Output = Self;
+
+    fn add(self, other: WorkingDuration) -> Self::Output {
+        self + Self::from(other)
+    }
+}
+
+impl<T> AddAssign<T> for TotalDuration
+where
+    Self: Add<T, Output = Self>,
+{
+    fn add_assign(&mut self, other: T) {
+        *self = *self + other;
+    }
+}
+
+impl<T> Sum<T> for TotalDuration
+where
+    Self: Add<T, Output = Self>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::working_duration;
+
+    #[test]
+    fn test_display() {
+        let total = [working_duration!(99:30), working_duration!(40:45)]
+            .into_iter()
+            .sum::<TotalDuration>();
+
+        assert_eq!(total, TotalDuration::from_mins(140 * 60 + 15));
+        assert_eq!(total.to_string(), "140:15");
+        assert_eq!(TotalDuration::from_mins(5).to_string(), "00:05");
+    }
+}
@@ -0,0 +1,196 @@
+use core::fmt;
+
+use derive_more::Display;
+
+use crate::input::toml_input;
+use crate::report::Table;
+use crate::time::{Month, Year};
+
+/// How the vacation taken so far compares to the entitlement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum VacationState {
+    /// The vacation taken so far matches the months of the contract.
+    #[display(fmt = "on track")]
+    OnTrack,
+    /// Less vacation has been taken than months have passed.
+    #[display(fmt = "{} month(s) behind", _0)]
+    Under(usize),
+    /// More vacation has been taken than the contract is entitled to.
+    #[display(fmt = "{} month(s) too many", _0)]
+    Over(usize),
+}
+
+/// The vacation of a single contract in a year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacationAccount {
+    department: String,
+    // the months in which the contract is active
+    entitled: Vec<Month>,
+    // the months for which a month file exists
+    covered: Vec<Month>,
+    taken: usize,
+}
+
+impl VacationAccount {
+    #[must_use]
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// For how many months vacation can be taken in the year.
+    #[must_use]
+    pub fn entitled_months(&self) -> usize {
+        self.entitled.len()
+    }
+
+    /// For how many months vacation has been taken.
+    #[must_use]
+    pub const fn taken_months(&self) -> usize {
+        self.taken
+    }
+
+    /// The months of the contract, for which no month file exists yet.
+    #[must_use]
+    pub fn remaining_months(&self) -> Vec<Month> {
+        self.entitled
+            .iter()
+            .copied()
+            .filter(|month| !self.covered.contains(month))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn state(&self) -> VacationState {
+        if self.taken > self.entitled_months() {
+            VacationState::Over(self.taken - self.entitled_months())
+        } else if self.taken < self.covered.len() {
+            VacationState::Under(self.covered.len() - self.taken)
+        } else {
+            VacationState::OnTrack
+        }
+    }
+
+    /// Suggests for how many months vacation should be taken in each of the
+    /// remaining months, so that the whole entitlement is used up.
+    #[must_use]
+    pub fn suggestion(&self) -> Vec<(Month, usize)> {
+        let remaining = self.remaining_months();
+        let open = self.entitled_months().saturating_sub(self.taken);
+
+        if remaining.is_empty() {
+            return Vec::new();
+        }
+
+        let (per_month, extra) = (open / remaining.len(), open % remaining.len());
+
+        remaining
+            .into_iter()
+            .enumerate()
+            .map(|(i, month)| (month, per_month + usize::from(i < extra)))
+            .collect()
+    }
+}
+
+/// Tracks how much vacation has been taken in a year for each contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacationLedger {
+    year: Year,
+    accounts: Vec<VacationAccount>,
+}
+
+impl VacationLedger {
+    /// Collects the holidays of the month files in the `year`, month files of
+    /// other years are ignored.
+    pub fn new<'a>(
+        global: &toml_input::Global,
+        year: Year,
+        months: impl IntoIterator<Item = &'a toml_input::Month>,
+    ) -> anyhow::Result<Self> {
+        let mut accounts = global
+            .contracts()
+            .map(|contract| VacationAccount {
+                department: contract.department().to_string(),
+                entitled: Month::months()
+                    .into_iter()
+                    .filter(|month| contract.is_active_in(year, *month))
+                    .collect(),
+                covered: Vec::new(),
+                taken: 0,
+            })
+            .filter(|account| !account.entitled.is_empty())
+            .collect::<Vec<_>>();
+
+        for month in months {
+            if month.general().year() != year {
+                continue;
+            }
+
+            for month in month.split_by_department()? {
+                let department = month.general().department();
+                let account = accounts
+                    .iter_mut()
+                    .find(|account| account.department == department)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("no contract for department `{}` in {}", department, year)
+                    })?;
+
+                if !account.covered.contains(&month.general().month()) {
+                    account.covered.push(month.general().month());
+                }
+
+                account.taken += month.holiday().map_or(0, toml_input::Holiday::months);
+            }
+        }
+
+        Ok(Self { year, accounts })
+    }
+
+    #[must_use]
+    pub const fn year(&self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub fn accounts(&self) -> &[VacationAccount] {
+        &self.accounts
+    }
+}
+
+impl fmt::Display for VacationLedger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new(["Department", "Entitled", "Taken", "Remaining", "State"]);
+
+        for account in &self.accounts {
+            table.push_row([
+                account.department().to_string(),
+                account.entitled_months().to_string(),
+                account.taken_months().to_string(),
+                account.remaining_months().len().to_string(),
+                account.state().to_string(),
+            ]);
+        }
+
+        writeln!(f, "Vacation in {}", self.year)?;
+        write!(f, "{}", table)?;
+
+        for account in &self.accounts {
+            let suggestion = account
+                .suggestion()
+                .into_iter()
+                .filter(|(_, months)| *months > 0)
+                .map(|(month, months)| format!("{:02}: {}", month.as_usize(), months))
+                .collect::<Vec<_>>();
+
+            if !suggestion.is_empty() {
+                writeln!(
+                    f,
+                    "suggested months for `{}`: {}",
+                    account.department(),
+                    suggestion.join(", ")
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
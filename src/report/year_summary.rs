@@ -0,0 +1,305 @@
+use core::fmt;
+
+use crate::input::Config;
+use crate::report::{Table, TotalDuration};
+use crate::time::{Month, Year};
+
+/// Formats a signed amount of minutes like `-01:30`.
+fn format_balance(minutes: i64) -> String {
+    let sign = if minutes < 0 { "-" } else { "+" };
+    let total = TotalDuration::from_mins(minutes.unsigned_abs());
+
+    format!("{}{}", sign, total)
+}
+
+/// The hours of a single month of a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryRow {
+    month: Month,
+    department: String,
+    expected: TotalDuration,
+    worked: TotalDuration,
+    vacation: TotalDuration,
+    transfer_in: TotalDuration,
+    transfer_out: TotalDuration,
+    balance: i64,
+}
+
+impl SummaryRow {
+    #[must_use]
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    #[must_use]
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// How much should have been worked according to the contract.
+    #[must_use]
+    pub const fn expected(&self) -> TotalDuration {
+        self.expected
+    }
+
+    /// The time of all entries that are not vacation.
+    #[must_use]
+    pub const fn worked(&self) -> TotalDuration {
+        self.worked
+    }
+
+    #[must_use]
+    pub const fn vacation(&self) -> TotalDuration {
+        self.vacation
+    }
+
+    /// The time that has been transferred from the previous month.
+    #[must_use]
+    pub const fn transfer_in(&self) -> TotalDuration {
+        self.transfer_in
+    }
+
+    /// The time that is transferred to the next month.
+    #[must_use]
+    pub const fn transfer_out(&self) -> TotalDuration {
+        self.transfer_out
+    }
+
+    /// The difference in minutes between the accounted and the expected time
+    /// of the contract, from the start of the year until the end of this month.
+    #[must_use]
+    pub const fn balance(&self) -> i64 {
+        self.balance
+    }
+
+    fn cells(&self) -> [String; 8] {
+        [
+            format!("{:02}", self.month),
+            self.department.clone(),
+            self.expected.to_string(),
+            self.worked.to_string(),
+            self.vacation.to_string(),
+            self.transfer_in.to_string(),
+            self.transfer_out.to_string(),
+            format_balance(self.balance),
+        ]
+    }
+}
+
+/// An overview of all months of a year, for each contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearSummary {
+    year: Year,
+    rows: Vec<SummaryRow>,
+}
+
+impl YearSummary {
+    const HEADER: [&'static str; 8] = [
+        "Month",
+        "Department",
+        "Expected",
+        "Worked",
+        "Vacation",
+        "Transfer in",
+        "Transfer out",
+        "Balance",
+    ];
+
+    /// Summarizes the configs of the `year`, configs of other years are
+    /// ignored.
+    #[must_use]
+    pub fn from_configs<'a>(year: Year, configs: impl IntoIterator<Item = &'a Config>) -> Self {
+        let mut configs = configs
+            .into_iter()
+            .filter(|config| config.month().year() == year)
+            .collect::<Vec<_>>();
+        configs.sort_by(|left, right| {
+            (left.department(), left.month().month())
+                .cmp(&(right.department(), right.month().month()))
+        });
+
+        let mut rows: Vec<SummaryRow> = Vec::new();
+        for config in configs {
+            let month = config.month();
+            let resolved = month.resolve();
+
+            let (mut worked, mut vacation) = (TotalDuration::default(), TotalDuration::default());
+            for (_, entry) in resolved.entries() {
+                if entry.is_vacation() {
+                    vacation += entry.work_duration();
+                } else {
+                    worked += entry.work_duration();
+                }
+            }
+
+            let expected = TotalDuration::from(month.real_expected_working_duration());
+            let transfer_in = TotalDuration::from(month.transfer().previous());
+            let transfer_out = TotalDuration::from(month.transfer().next());
+
+            let previous_balance = rows
+                .last()
+                .filter(|row| row.department == config.department())
+                .map_or(0, |row| row.balance);
+
+            let accounted = worked + vacation + transfer_in;
+            let balance = previous_balance + accounted.as_mins() as i64
+                - transfer_out.as_mins() as i64
+                - expected.as_mins() as i64;
+
+            rows.push(SummaryRow {
+                month: month.month(),
+                department: config.department().to_string(),
+                expected,
+                worked,
+                vacation,
+                transfer_in,
+                transfer_out,
+                balance,
+            });
+        }
+
+        Self { year, rows }
+    }
+
+    #[must_use]
+    pub const fn year(&self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub fn rows(&self) -> &[SummaryRow] {
+        &self.rows
+    }
+
+    /// Returns the totals of each contract, the balance is the one at the end
+    /// of the year.
+    #[must_use]
+    pub fn totals(&self) -> Vec<SummaryRow> {
+        let mut result: Vec<SummaryRow> = Vec::new();
+
+        for row in &self.rows {
+            match result.last_mut() {
+                Some(total) if total.department == row.department => {
+                    total.expected += row.expected;
+                    total.worked += row.worked;
+                    total.vacation += row.vacation;
+                    total.transfer_in += row.transfer_in;
+                    total.transfer_out += row.transfer_out;
+                    total.balance = row.balance;
+                }
+                _ => result.push(row.clone()),
+            }
+        }
+
+        result
+    }
+
+    /// Returns the summary as comma separated values, the totals are in the
+    /// rows where the month is `total`.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let escape = |cell: &str| {
+            if cell.contains([',', '"', '\n']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        };
+
+        let mut result = Self::HEADER.join(",");
+        result.push('\n');
+
+        let totals = self.totals().into_iter().map(|total| {
+            let mut cells = total.cells();
+            cells[0] = "total".to_string();
+            cells
+        });
+
+        for cells in self.rows.iter().map(SummaryRow::cells).chain(totals) {
+            let line = cells
+                .iter()
+                .map(|cell| escape(cell))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            result.push_str(&line);
+            result.push('\n');
+        }
+
+        result
+    }
+
+    /// Returns a latex document with a page that contains the summary.
+    #[must_use]
+    pub fn to_latex(&self) -> String {
+        let row = |cells: [String; 8]| {
+            let cells = cells
+                .iter()
+                .map(|cell| v_latexescape::escape(cell).to_string())
+                .collect::<Vec<_>>();
+
+            format!("{} \\\\\n", cells.join(" & "))
+        };
+
+        let mut result = String::new();
+        result.push_str("\\documentclass[a4paper]{article}\n");
+        result.push_str("\\usepackage[T1]{fontenc}\n");
+        result.push_str("\\usepackage[margin=2cm]{geometry}\n");
+        result.push_str("\\begin{document}\n");
+        result.push_str(&format!("\\section*{{Summary {}}}\n", self.year));
+        result.push_str("\\begin{tabular}{llrrrrrr}\n");
+        result.push_str("\\hline\n");
+        result.push_str(&row(Self::HEADER.map(String::from)));
+        result.push_str("\\hline\n");
+
+        for summary_row in &self.rows {
+            result.push_str(&row(summary_row.cells()));
+        }
+
+        result.push_str("\\hline\n");
+        for total in self.totals() {
+            let mut cells = total.cells();
+            cells[0] = "Total".to_string();
+            result.push_str(&row(cells));
+        }
+
+        result.push_str("\\hline\n");
+        result.push_str("\\end{tabular}\n");
+        result.push_str("\\end{document}\n");
+
+        result
+    }
+}
+
+impl fmt::Display for YearSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new(Self::HEADER);
+
+        for row in &self.rows {
+            table.push_row(row.cells());
+        }
+
+        for total in self.totals() {
+            let mut cells = total.cells();
+            cells[0] = "total".to_string();
+            table.push_row(cells);
+        }
+
+        writeln!(f, "Summary of {}", self.year)?;
+        write!(f, "{}", table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_format_balance() {
+        assert_eq!(format_balance(0), "+00:00");
+        assert_eq!(format_balance(90), "+01:30");
+        assert_eq!(format_balance(-90), "-01:30");
+    }
+}
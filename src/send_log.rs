@@ -0,0 +1,225 @@
+//! A record of the time sheets that have been sent, so that a month is not
+//! submitted twice by accident.
+
+use core::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::input::Config;
+use crate::time::{Date, Month, TimeStamp, Year};
+use crate::utils;
+
+/// Returns the sha-256 hash of the parts as hex string.
+fn hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Returns the sha-256 hash of the json files from which the time sheet is
+/// generated.
+///
+/// The pdf itself can not be hashed, because it contains the time at which it
+/// has been compiled.
+pub fn hash_input(config: &Config) -> serde_json::Result<String> {
+    Ok(hash(&[&config.to_global_json()?, &config.to_month_json()?]))
+}
+
+/// A time sheet that has been sent.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SentMail {
+    year: Year,
+    month: Month,
+    department: String,
+    /// The start of the contract, tells contracts of the same department apart.
+    #[serde(with = "utils::serde_toml_local_date")]
+    contract_start: Date,
+    recipients: Vec<String>,
+    #[serde(with = "utils::serde_toml_local_date")]
+    date: Date,
+    time: TimeStamp,
+    /// The hash of the time sheet, see [`hash_input`].
+    input_hash: String,
+}
+
+impl SentMail {
+    #[must_use]
+    pub fn new(
+        config: &Config,
+        recipients: Vec<String>,
+        (date, time): (Date, TimeStamp),
+        input_hash: impl Into<String>,
+    ) -> Self {
+        Self {
+            year: config.month().year(),
+            month: config.month().month(),
+            department: config.department().to_string(),
+            contract_start: config.contract_start_date(),
+            recipients,
+            date,
+            time,
+            input_hash: input_hash.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    #[must_use]
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    #[must_use]
+    pub fn contract_start(&self) -> Date {
+        self.contract_start
+    }
+
+    #[must_use]
+    pub fn recipients(&self) -> &[String] {
+        &self.recipients
+    }
+
+    #[must_use]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    #[must_use]
+    pub fn time(&self) -> TimeStamp {
+        self.time
+    }
+
+    #[must_use]
+    pub fn input_hash(&self) -> &str {
+        &self.input_hash
+    }
+}
+
+impl fmt::Display for SentMail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the time sheet of `{}` (contract from {}) for {:04}-{:02} has been sent to {} on {} at {}",
+            self.department,
+            self.contract_start,
+            self.year,
+            self.month,
+            self.recipients.join(", "),
+            self.date,
+            self.time
+        )
+    }
+}
+
+/// All time sheets that have been sent, in the order they were sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SendLog {
+    #[serde(default)]
+    sent: Vec<SentMail>,
+}
+
+impl SendLog {
+    /// The name of the file, in which the log is stored next to the month files.
+    ///
+    /// It does not end with `.toml`, so that it is not taken for a month file.
+    pub const FILE_NAME: &'static str = ".time-sheet-sent.log";
+
+    /// Loads the log, returns an empty log if the file does not exist.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        Ok(toml::from_str(&utils::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        utils::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[SentMail] {
+        &self.sent
+    }
+
+    /// Returns when the time sheet of the month and contract has been sent the
+    /// last time.
+    #[must_use]
+    pub fn find(&self, config: &Config) -> Option<&SentMail> {
+        let (year, month) = (config.month().year(), config.month().month());
+
+        self.sent.iter().rev().find(|sent| {
+            sent.year == year
+                && sent.month == month
+                && sent.department == config.department()
+                && sent.contract_start == config.contract_start_date()
+        })
+    }
+
+    pub fn record(&mut self, sent: SentMail) {
+        self.sent.push(sent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::time_stamp;
+
+    fn sent(month: Month, department: &str, input_hash: &str) -> SentMail {
+        SentMail {
+            year: Year::new(2022),
+            month,
+            department: department.to_string(),
+            contract_start: Date::new(Year::new(2022), Month::October, 1).unwrap(),
+            recipients: vec!["Office <office@example.com>".to_string()],
+            date: Date::new(Year::new(2022), Month::December, 1).unwrap(),
+            time: time_stamp!(10:15),
+            input_hash: input_hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SendLog::FILE_NAME);
+
+        assert_eq!(SendLog::load(&path).unwrap(), SendLog::default());
+
+        let mut log = SendLog::default();
+        log.record(sent(Month::November, "MENSA", "a"));
+        log.record(sent(Month::November, "IANA", "b"));
+        log.save(&path).unwrap();
+
+        assert_eq!(SendLog::load(&path).unwrap(), log);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            hash(&[]),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(hash(&["ab", "c"]), hash(&["abc"]));
+    }
+}
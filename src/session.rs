@@ -0,0 +1,281 @@
+//! A running work session that has been started with `time-sheet start`
+//! and will be converted into entries with `time-sheet stop`.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::input::{json_input, toml_input};
+use crate::time::{Date, TimeSpan, TimeStamp, WorkingDuration};
+use crate::utils;
+use crate::{time_stamp, working_duration};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Session {
+    action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    department: Option<String>,
+    #[serde(with = "utils::serde_toml_local_date")]
+    date: Date,
+    start: TimeStamp,
+}
+
+impl Session {
+    /// The default granularity to which the start and end of a session are rounded.
+    pub const DEFAULT_ROUNDING: WorkingDuration = working_duration!(00:05);
+
+    #[must_use]
+    pub fn new(
+        action: impl Into<String>,
+        department: Option<String>,
+        date: Date,
+        start: TimeStamp,
+    ) -> Self {
+        Self {
+            action: action.into(),
+            department,
+            date,
+            start,
+        }
+    }
+
+    /// Loads the running session, returns `None` if there is none.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Option<Self>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        Ok(Some(toml::from_str(&utils::read_to_string(path)?)?))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        utils::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    #[must_use]
+    pub fn department(&self) -> Option<&str> {
+        self.department.as_deref()
+    }
+
+    #[must_use]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    #[must_use]
+    pub fn start(&self) -> TimeStamp {
+        self.start
+    }
+
+    /// Stops the session at the given date and time and returns the entries
+    /// that have been worked.
+    ///
+    /// The start and end are rounded to the nearest multiple of `rounding`.
+    /// Sessions that span multiple days are split into one entry per day and
+    /// if an entry is long enough to legally require a pause, a pause is added.
+    /// A time stamp can not be `24:00`, so the entry of a day that does not
+    /// end the session ends at `23:59` and one minute is lost per midnight.
+    ///
+    /// The result is empty, if nothing has been worked after rounding.
+    pub fn stop(
+        &self,
+        date: Date,
+        end: TimeStamp,
+        rounding: WorkingDuration,
+    ) -> anyhow::Result<Vec<(Date, toml_input::Entry)>> {
+        if (date, end) < (self.date, self.start) {
+            return Err(anyhow::anyhow!(
+                "the session can not end ({} {}) before it started ({} {})",
+                date,
+                end,
+                self.date,
+                self.start
+            ));
+        }
+
+        let mut result = Vec::new();
+
+        for current in self.date..=date {
+            let start = {
+                if current == self.date {
+                    round(self.start, rounding)
+                } else {
+                    time_stamp!(00:00)
+                }
+            };
+
+            let end = {
+                if current == date {
+                    round(end, rounding)
+                } else {
+                    time_stamp!(23:59)
+                }
+            };
+
+            // skip days where nothing has been worked after rounding
+            if end <= start {
+                continue;
+            }
+
+            let span = TimeSpan::new(start, end);
+            let pause = json_input::Entry::required_pause(span.duration());
+
+            result.push((
+                current,
+                toml_input::Entry::new(current.day(), self.action.clone(), span, pause, None),
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Rounds the time stamp to the nearest multiple of `rounding`.
+fn round(time: TimeStamp, rounding: WorkingDuration) -> TimeStamp {
+    let rounding = rounding.as_mins();
+    if rounding == 0 {
+        return time;
+    }
+
+    let mins = ((time.as_mins() + rounding / 2) / rounding) * rounding;
+    let mins = mins.min(time_stamp!(23:59).as_mins());
+
+    TimeStamp::new((mins / 60) as u8, (mins % 60) as u8).expect("the rounded time should be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::date;
+
+    #[test]
+    fn test_round() {
+        let rounding = working_duration!(00:05);
+
+        assert_eq!(round(time_stamp!(13:12), rounding), time_stamp!(13:10));
+        assert_eq!(round(time_stamp!(13:13), rounding), time_stamp!(13:15));
+        assert_eq!(round(time_stamp!(13:15), rounding), time_stamp!(13:15));
+        assert_eq!(round(time_stamp!(23:58), rounding), time_stamp!(23:59));
+        assert_eq!(
+            round(time_stamp!(13:13), working_duration!(00:00)),
+            time_stamp!(13:13)
+        );
+    }
+
+    #[test]
+    fn test_stop() {
+        let session = Session::new("Korrektur", None, date!(2022:12:13), time_stamp!(13:12));
+
+        let entries = session
+            .stop(
+                date!(2022:12:13),
+                time_stamp!(14:29),
+                Session::DEFAULT_ROUNDING,
+            )
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        let (date, entry) = &entries[0];
+        assert_eq!(*date, date!(2022:12:13));
+        assert_eq!(entry.day(), 13);
+        assert_eq!(entry.action(), "Korrektur");
+        assert_eq!(entry.start(), time_stamp!(13:10));
+        assert_eq!(entry.end(), time_stamp!(14:30));
+        assert_eq!(entry.pause(), None);
+    }
+
+    #[test]
+    fn test_stop_adds_pause() {
+        let session = Session::new("Korrektur", None, date!(2022:12:13), time_stamp!(08:00));
+
+        let entries = session
+            .stop(
+                date!(2022:12:13),
+                time_stamp!(17:30),
+                Session::DEFAULT_ROUNDING,
+            )
+            .unwrap();
+
+        assert_eq!(entries[0].1.end(), time_stamp!(17:30));
+        assert_eq!(entries[0].1.pause(), Some(working_duration!(00:45)));
+
+        let entries = session
+            .stop(
+                date!(2022:12:13),
+                time_stamp!(14:30),
+                Session::DEFAULT_ROUNDING,
+            )
+            .unwrap();
+
+        assert_eq!(entries[0].1.pause(), Some(working_duration!(00:30)));
+    }
+
+    #[test]
+    fn test_stop_nothing_left() {
+        let session = Session::new("Korrektur", None, date!(2022:12:13), time_stamp!(13:13));
+
+        assert!(session
+            .stop(
+                date!(2022:12:13),
+                time_stamp!(13:16),
+                Session::DEFAULT_ROUNDING
+            )
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_stop_on_next_day() {
+        let session = Session::new("Korrektur", None, date!(2022:12:31), time_stamp!(22:00));
+
+        let entries = session
+            .stop(
+                date!(2023:01:01),
+                time_stamp!(01:00),
+                Session::DEFAULT_ROUNDING,
+            )
+            .unwrap();
+
+        assert_eq!(
+            entries
+                .iter()
+                .map(|(date, entry)| (*date, entry.start(), entry.end()))
+                .collect::<Vec<_>>(),
+            vec![
+                (date!(2022:12:31), time_stamp!(22:00), time_stamp!(23:59)),
+                (date!(2023:01:01), time_stamp!(00:00), time_stamp!(01:00)),
+            ]
+        );
+
+        assert!(session
+            .stop(
+                date!(2022:12:30),
+                time_stamp!(23:00),
+                Session::DEFAULT_ROUNDING
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let session = Session::new(
+            "Korrektur",
+            Some("IANA".to_string()),
+            date!(2022:12:13),
+            time_stamp!(13:12),
+        );
+
+        let serialized = toml::to_string(&session).unwrap();
+        assert_eq!(toml::from_str::<Session>(&serialized).unwrap(), session);
+    }
+}
@@ -0,0 +1,366 @@
+//! A small template engine for file names, subjects and the text of emails.
+//!
+//! A placeholder is written as `{name}` or `{name:format}`, `{{` and `}}` are a
+//! literal `{` and `}`. The supported formats depend on the value:
+//! - numbers can be zero-padded, for example `{staff_id:08}`
+//! - years can be zero-padded as well, `{year:02}` are the last two digits
+//! - months can be zero-padded or written out with `{month:de}` or `{month:en}`
+
+use core::fmt;
+
+use thiserror::Error;
+
+use crate::time::{Month, Year};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    #[error("unknown placeholder `{{{name}}}` in \"{template}\"")]
+    UnknownPlaceholder { name: String, template: String },
+    #[error("invalid format `{format}` for `{{{name}}}` in \"{template}\"")]
+    InvalidFormat {
+        name: String,
+        format: String,
+        template: String,
+    },
+    #[error("unclosed `{{` in \"{template}\"")]
+    Unclosed { template: String },
+    #[error("unexpected `}}` in \"{template}\", use `}}}}` for a literal `}}`")]
+    UnexpectedClose { template: String },
+}
+
+/// A value that can be inserted into a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Text(String),
+    Number(usize),
+    Year(Year),
+    Month(Month),
+}
+
+impl Value {
+    fn month_name(month: Month, language: &str) -> Option<&'static str> {
+        const GERMAN: [&str; 12] = [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ];
+        const ENGLISH: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+
+        match language {
+            "de" => Some(GERMAN[month.as_usize() - 1]),
+            "en" => Some(ENGLISH[month.as_usize() - 1]),
+            _ => None,
+        }
+    }
+
+    /// Formats the value, returns `None` if the format is not supported.
+    fn format(&self, format: Option<&str>) -> Option<String> {
+        let width = format
+            .map(|format| {
+                format
+                    .bytes()
+                    .all(|byte| byte.is_ascii_digit())
+                    .then(|| format.parse::<usize>().ok())
+                    .flatten()
+            })
+            .unwrap_or(Some(0));
+
+        match (self, format, width) {
+            (Self::Text(text), None, _) => Some(text.clone()),
+            (Self::Number(number), _, Some(width)) => Some(format!("{:0width$}", number)),
+            (Self::Year(year), _, Some(width)) => {
+                let year = year.as_usize();
+                // `{year:02}` are the last two digits
+                let year = if width > 0 && width < 4 {
+                    year % 10_usize.pow(width as u32)
+                } else {
+                    year
+                };
+
+                Some(format!("{:0width$}", year))
+            }
+            (Self::Month(month), _, Some(width)) => Some(format!("{:0width$}", month.as_usize())),
+            (Self::Month(month), Some(language), None) => {
+                Self::month_name(*month, language).map(ToString::to_string)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<Year> for Value {
+    fn from(value: Year) -> Self {
+        Self::Year(value)
+    }
+}
+
+impl From<Month> for Value {
+    fn from(value: Month) -> Self {
+        Self::Month(value)
+    }
+}
+
+/// The values of the placeholders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateContext {
+    values: Vec<(String, Value)>,
+}
+
+impl TemplateContext {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a placeholder, an existing one with the same name is replaced.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        let (name, value) = (name.into(), value.into());
+
+        if let Some((_, existing)) = self.values.iter_mut().find(|(key, _)| *key == name) {
+            *existing = value;
+        } else {
+            self.values.push((name, value));
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// A text with placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template<'a> {
+    template: &'a str,
+}
+
+impl<'a> Template<'a> {
+    #[must_use]
+    pub const fn new(template: &'a str) -> Self {
+        Self { template }
+    }
+
+    /// Replaces the placeholders with the values of the context.
+    pub fn render(&self, context: &TemplateContext) -> Result<String, TemplateError> {
+        self.render_with(context, ToString::to_string)
+    }
+
+    /// Like [`Template::render`], but the inserted values are passed through
+    /// `escape` (for example to escape html).
+    pub fn render_with(
+        &self,
+        context: &TemplateContext,
+        escape: impl Fn(&str) -> String,
+    ) -> Result<String, TemplateError> {
+        let mut result = String::with_capacity(self.template.len());
+        let mut rest = self.template;
+
+        while let Some(index) = rest.find(['{', '}']) {
+            result.push_str(&rest[..index]);
+            let (c, after) = (rest.as_bytes()[index], &rest[index + 1..]);
+
+            if c == b'}' {
+                if let Some(after) = after.strip_prefix('}') {
+                    result.push('}');
+                    rest = after;
+                    continue;
+                }
+
+                return Err(TemplateError::UnexpectedClose {
+                    template: self.template.to_string(),
+                });
+            }
+
+            if let Some(after) = after.strip_prefix('{') {
+                result.push('{');
+                rest = after;
+                continue;
+            }
+
+            let end = after.find('}').ok_or_else(|| TemplateError::Unclosed {
+                template: self.template.to_string(),
+            })?;
+            let placeholder = &after[..end];
+            let (name, format) = match placeholder.split_once(':') {
+                Some((name, format)) => (name, Some(format)),
+                None => (placeholder, None),
+            };
+
+            let value = context
+                .get(name)
+                .ok_or_else(|| TemplateError::UnknownPlaceholder {
+                    name: name.to_string(),
+                    template: self.template.to_string(),
+                })?;
+
+            let formatted = value
+                .format(format)
+                .ok_or_else(|| TemplateError::InvalidFormat {
+                    name: name.to_string(),
+                    format: format.unwrap_or_default().to_string(),
+                    template: self.template.to_string(),
+                })?;
+
+            result.push_str(&escape(&formatted));
+            rest = &after[end + 1..];
+        }
+
+        result.push_str(rest);
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Template<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn context() -> TemplateContext {
+        TemplateContext::new()
+            .with("name", "Max Mustermann")
+            .with("staff_id", 1234567)
+            .with("year", Year::new(2023))
+            .with("month", Month::March)
+    }
+
+    fn render(template: &str) -> Result<String, TemplateError> {
+        Template::new(template).render(&context())
+    }
+
+    #[test]
+    fn test_plain_placeholders() {
+        assert_eq!(
+            render("{name} ({staff_id}) {year}-{month}"),
+            Ok("Max Mustermann (1234567) 2023-3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_zero_padding() {
+        assert_eq!(
+            render("{year:04}-{month:02} {year:02} {staff_id:09}"),
+            Ok("2023-03 23 001234567".to_string())
+        );
+    }
+
+    #[test]
+    fn test_month_names() {
+        assert_eq!(
+            render("{month:de} / {month:en}"),
+            Ok("März / March".to_string())
+        );
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        assert_eq!(render("{{name}} }}"), Ok("{name} }".to_string()));
+    }
+
+    #[test]
+    fn test_escape_values() {
+        let context = TemplateContext::new().with("name", "<b>");
+
+        assert_eq!(
+            Template::new("<i>{name}</i>")
+                .render_with(&context, |value| value.replace('<', "&lt;")),
+            Ok("<i>&lt;b></i>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            render("{nam}"),
+            Err(TemplateError::UnknownPlaceholder {
+                name: "nam".to_string(),
+                template: "{nam}".to_string(),
+            })
+        );
+        assert_eq!(
+            render("{name:02}"),
+            Err(TemplateError::InvalidFormat {
+                name: "name".to_string(),
+                format: "02".to_string(),
+                template: "{name:02}".to_string(),
+            })
+        );
+        assert_eq!(
+            render("{month:fr}"),
+            Err(TemplateError::InvalidFormat {
+                name: "month".to_string(),
+                format: "fr".to_string(),
+                template: "{month:fr}".to_string(),
+            })
+        );
+        assert_eq!(
+            render("{name"),
+            Err(TemplateError::Unclosed {
+                template: "{name".to_string(),
+            })
+        );
+        assert_eq!(
+            render("name}"),
+            Err(TemplateError::UnexpectedClose {
+                template: "name}".to_string(),
+            })
+        );
+    }
+}
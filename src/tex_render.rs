@@ -1,24 +1,133 @@
+use std::env;
+use std::ffi::OsString;
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
+use log::debug;
 use tempfile::TempDir;
 use thiserror::Error;
 
+use crate::input::toml_input::{LatexEngine, LatexOptions};
+use crate::latex_log::LatexDiagnostic;
+
 #[derive(Debug, Error)]
 pub enum RenderingError {
     #[error(transparent)]
     RunError(io::Error),
     #[error(transparent)]
     ReadOutputFile(io::Error),
+    #[error("`{program}` was not found, it is needed for the {engine} engine. Install it or set `latex_mk_path` or `engine` in the `[config]` section of the global file")]
+    ProgramNotFound {
+        program: String,
+        engine: LatexEngine,
+    },
+    #[error("`{program}` did not finish within {} seconds", timeout.as_secs())]
+    Timeout { program: String, timeout: Duration },
+    #[error("`{program}` failed:\n{diagnostic}")]
+    Latex {
+        program: String,
+        diagnostic: Box<LatexDiagnostic>,
+    },
+}
+
+/// Returns whether the program can be run, either because it is a path to a
+/// file or because it can be found in the `PATH`.
+fn is_program_available(program: &Path) -> bool {
+    if program.components().count() > 1 {
+        return program.is_file();
+    }
+
+    let extensions: &[&str] = if cfg!(windows) {
+        &["exe", "bat", "cmd"]
+    } else {
+        &[]
+    };
+
+    env::var_os("PATH").is_some_and(|paths| {
+        env::split_paths(&paths).any(|dir| {
+            let path = dir.join(program);
+
+            path.is_file()
+                || extensions
+                    .iter()
+                    .any(|extension| path.with_extension(extension).is_file())
+        })
+    })
+}
+
+/// Reads everything from the pipe on another thread, so that the process
+/// does not block when the pipe is full.
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+/// Kills the process group of the child, which has been spawned as the leader
+/// of a new group.
+///
+/// Killing only the child would leave the engine run by latexmk running.
+#[cfg(unix)]
+fn kill(child: &mut Child) -> io::Result<()> {
+    let group = libc::pid_t::try_from(child.id())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+    // SAFETY: killpg has no memory safety requirements
+    if unsafe { libc::killpg(group, libc::SIGKILL) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()
+}
+
+/// Waits for the child to exit, it and the processes it started are killed
+/// if it takes longer than the timeout.
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+) -> io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if Instant::now() >= deadline {
+            kill(child)?;
+            child.wait()?;
+            return Ok(None);
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
 }
 
 pub struct TexRender {
-    /// Path to latexmk.
-    latex_mk_path: PathBuf,
-    /// Whether or not to use XeLaTeX.
-    use_xelatex: bool,
+    /// Which program compiles the LaTeX file.
+    engine: LatexEngine,
+    /// Path to latexmk or tectonic.
+    program: PathBuf,
+    /// Arguments in addition to the default ones.
+    args: Vec<String>,
+    /// After how long the program is stopped.
+    timeout: Option<Duration>,
     /// Whether or not to allow shell escaping.
     allow_shell_escape: bool,
     /// Temporary directory holding assets to be included.
@@ -31,9 +140,13 @@ impl TexRender {
         let working_dir = TempDir::new()?;
         fs::write(working_dir.path().join("input.tex"), source.as_ref())?;
 
+        let engine = LatexEngine::default();
+
         Ok(Self {
-            latex_mk_path: "latexmk".into(),
-            use_xelatex: true,
+            engine,
+            program: engine.default_program().into(),
+            args: Vec::new(),
+            timeout: None,
             allow_shell_escape: false,
             working_dir,
             preserve_dir: None,
@@ -56,39 +169,128 @@ impl TexRender {
         self
     }
 
-    pub fn latex_mk_path(&mut self, latex_mk_path: impl Into<PathBuf>) -> &mut Self {
-        self.latex_mk_path = latex_mk_path.into();
+    /// Applies the engine, the program, the arguments and the timeout.
+    pub fn options(&mut self, options: &LatexOptions) -> &mut Self {
+        self.engine = options.engine();
+        self.program = options.program();
+        self.args = options.args().to_vec();
+        self.timeout = options.timeout();
         self
     }
 
+    /// Checks that the program and for latexmk the engine it runs are
+    /// installed.
+    fn check_programs(&self) -> Result<(), RenderingError> {
+        let mut required = vec![self.program.clone()];
+
+        if self.engine.uses_latex_mk() {
+            required.push(PathBuf::from(self.engine.to_string()));
+        }
+
+        for program in required {
+            if !is_program_available(&program) {
+                return Err(RenderingError::ProgramNotFound {
+                    program: program.display().to_string(),
+                    engine: self.engine,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn args(&self, input_file: &Path) -> Vec<OsString> {
+        let mut args: Vec<OsString> = Vec::new();
+
+        match self.engine {
+            LatexEngine::Tectonic => {
+                args.extend(["--keep-logs".into(), "--outdir".into()]);
+                args.push(self.working_dir.path().into());
+
+                if self.allow_shell_escape {
+                    args.push("-Z".into());
+                    args.push("shell-escape".into());
+                }
+            }
+            engine => {
+                args.extend(
+                    [
+                        "-interaction=nonstopmode",
+                        "-halt-on-error",
+                        "-file-line-error",
+                        "-pdf",
+                        "-cd",
+                    ]
+                    .map(OsString::from),
+                );
+
+                match engine {
+                    LatexEngine::Xelatex => args.push("-xelatex".into()),
+                    LatexEngine::Lualatex => args.push("-lualatex".into()),
+                    // `-pdf` already uses pdflatex
+                    _ => {}
+                }
+
+                if !self.allow_shell_escape {
+                    args.push("-no-shell-escape".into());
+                }
+            }
+        }
+
+        args.extend(self.args.iter().map(OsString::from));
+        args.push(input_file.into());
+
+        args
+    }
+
     pub fn render(self) -> anyhow::Result<Vec<u8>> {
         let input_file = self.working_dir.path().join("input.tex");
         let output_file = self.working_dir.path().join("input.pdf");
 
-        let mut cmd = Command::new(&self.latex_mk_path);
-        cmd.args([
-            "-interaction=nonstopmode",
-            "-halt-on-error",
-            "-file-line-error",
-            "-pdf",
-            "-cd",
-        ]);
-
-        if self.use_xelatex {
-            cmd.arg("-xelatex");
-        }
+        self.check_programs()?;
 
-        if !self.allow_shell_escape {
-            cmd.arg("-no-shell-escape");
-        }
+        let mut command = Command::new(&self.program);
+        command
+            .args(self.args(&input_file))
+            .current_dir(self.working_dir.path())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        // the processes started by the program can then be killed together with it
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
 
-        cmd.arg(&input_file);
+        let mut child = command.spawn().map_err(|error| {
+            if error.kind() == io::ErrorKind::NotFound {
+                RenderingError::ProgramNotFound {
+                    program: self.program.display().to_string(),
+                    engine: self.engine,
+                }
+            } else {
+                RenderingError::RunError(error)
+            }
+        })?;
 
-        cmd.current_dir(self.working_dir.path());
+        let stdout = read_in_background(child.stdout.take());
+        let stderr = read_in_background(child.stderr.take());
 
-        let output = cmd.output().map_err(RenderingError::RunError)?;
+        let status =
+            wait_with_timeout(&mut child, self.timeout).map_err(RenderingError::RunError)?;
 
-        if !output.status.success() {
+        // after a timeout, a process that escaped the kill could keep the pipes
+        // open, the output is not needed then
+        let (stdout, stderr) = {
+            if status.is_some() {
+                (
+                    stdout.join().unwrap_or_default(),
+                    stderr.join().unwrap_or_default(),
+                )
+            } else {
+                (Vec::new(), Vec::new())
+            }
+        };
+
+        if !status.is_some_and(|status| status.success()) {
             if let Some(path) = self.preserve_dir {
                 fs::create_dir_all(&path)?;
                 fs_extra::dir::copy(
@@ -101,15 +303,196 @@ impl TexRender {
                     },
                 )?;
             }
-            // latexmk failed,
+
+            let Some(status) = status else {
+                return Err(RenderingError::Timeout {
+                    program: self.program.display().to_string(),
+                    timeout: self.timeout.unwrap_or_default(),
+                }
+                .into());
+            };
+
+            // the log is easier to understand than the output of latexmk
+            let log_file = self.working_dir.path().join("input.log");
+            if let Some(diagnostic) =
+                LatexDiagnostic::from_log_file(log_file, self.working_dir.path())
+            {
+                debug!("stdout: {}", String::from_utf8_lossy(&stdout));
+                debug!("stderr: {}", String::from_utf8_lossy(&stderr));
+
+                return Err(RenderingError::Latex {
+                    program: self.program.display().to_string(),
+                    diagnostic: Box::new(diagnostic),
+                }
+                .into());
+            }
+
+            // the engine failed,
             return Err(anyhow::anyhow!(
-                "latexmk failed with status: {:?}, stdout: {}, stderr: {}",
-                output.status.code(),
-                String::from_utf8_lossy(&output.stdout),
-                String::from_utf8_lossy(&output.stderr)
+                "{} failed with status: {:?}, stdout: {}, stderr: {}",
+                self.program.display(),
+                status.code(),
+                String::from_utf8_lossy(&stdout),
+                String::from_utf8_lossy(&stderr)
             ));
         }
 
         Ok(fs::read(output_file).map_err(RenderingError::ReadOutputFile)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_missing_program() {
+        let mut renderer = TexRender::from_bytes("").unwrap();
+        renderer.program = PathBuf::from("time-sheet-missing-latexmk");
+
+        let error = renderer.render().unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<RenderingError>(),
+            Some(RenderingError::ProgramNotFound { program, .. }) if program == "time-sheet-missing-latexmk"
+        ));
+    }
+
+    #[test]
+    fn test_tectonic_args() {
+        let mut renderer = TexRender::from_bytes("").unwrap();
+        let options: LatexOptions = toml::from_str(concat!(
+            "engine = \"tectonic\"\n",
+            "latex_args = [\"--print\"]\n",
+        ))
+        .unwrap();
+        renderer.options(&options);
+
+        let dir = renderer.working_dir.path().to_path_buf();
+        assert_eq!(
+            renderer.args(Path::new("input.tex")),
+            vec![
+                OsString::from("--keep-logs"),
+                OsString::from("--outdir"),
+                dir.into_os_string(),
+                OsString::from("--print"),
+                OsString::from("input.tex"),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_latex_error() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        // stands in for tectonic, writes a log with an error and fails
+        let program = dir.path().join("tectonic");
+        fs::write(
+            &program,
+            concat!(
+                "#!/bin/sh\n",
+                "printf './input.tex:1: Undefined control sequence.\\nl.1 \\\\foo\\n' > input.log\n",
+                "exit 1\n",
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut renderer = TexRender::from_bytes("\\foo\n").unwrap();
+        renderer.engine = LatexEngine::Tectonic;
+        renderer.program = program;
+
+        let error = renderer.render().unwrap_err();
+        let Some(RenderingError::Latex { diagnostic, .. }) = error.downcast_ref::<RenderingError>()
+        else {
+            panic!("expected a latex error, got {:?}", error);
+        };
+
+        let diagnostic = diagnostic.to_string();
+        assert!(diagnostic.starts_with("./input.tex:1: Undefined control sequence.\n"));
+        assert!(diagnostic.contains(">    1 | \\foo\n"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_timeout() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        // stands in for latexmk, but never finishes
+        let program = dir.path().join("latexmk");
+        fs::write(&program, "#!/bin/sh\nexec sleep 10\n").unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut renderer = TexRender::from_bytes("").unwrap();
+        renderer.engine = LatexEngine::Tectonic;
+        renderer.program = program;
+        renderer.timeout = Some(Duration::from_millis(200));
+
+        let error = renderer.render().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<RenderingError>(),
+            Some(RenderingError::Timeout { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_timeout_kills_engine() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let pid_file = dir.path().join("engine.pid");
+        // stands in for latexmk, which starts an engine that inherits the pipes
+        let program = dir.path().join("latexmk");
+        fs::write(
+            &program,
+            format!(
+                "#!/bin/sh
+sleep 10 &
+echo $! > '{}'
+wait
+",
+                pid_file.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut renderer = TexRender::from_bytes("").unwrap();
+        renderer.engine = LatexEngine::Tectonic;
+        renderer.program = program;
+        renderer.timeout = Some(Duration::from_millis(500));
+
+        let start = Instant::now();
+        let error = renderer.render().unwrap_err();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            error.downcast_ref::<RenderingError>(),
+            Some(RenderingError::Timeout { .. })
+        ));
+
+        // the engine has been killed as well, it might take a moment until it
+        // is gone
+        let pid = fs::read_to_string(&pid_file).unwrap();
+        let is_running = || {
+            Command::new("kill")
+                .args(["-0", pid.trim()])
+                .stderr(Stdio::null())
+                .status()
+                .unwrap()
+                .success()
+        };
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while is_running() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(50));
+        }
+        assert!(!is_running());
+    }
+}
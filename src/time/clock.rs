@@ -0,0 +1,26 @@
+use chrono::{Datelike, Local, Timelike};
+
+use crate::time::{Date, Month, TimeStamp, Year};
+
+/// Returns the current local date and time (without seconds).
+#[must_use]
+pub fn now() -> (Date, TimeStamp) {
+    let now = Local::now();
+
+    let date = Date::new(
+        Year::new(now.year() as usize),
+        Month::new(now.month() as usize),
+        now.day() as usize,
+    )
+    .expect("the local date should always be valid");
+    let time = TimeStamp::new(now.hour() as u8, now.minute() as u8)
+        .expect("the local time should always be valid");
+
+    (date, time)
+}
+
+/// Returns the current local date.
+#[must_use]
+pub fn today() -> Date {
+    now().0
+}
@@ -6,6 +6,7 @@ use core::str::FromStr;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::template::{Template, TemplateContext, TemplateError};
 use crate::time::{holiday, Month, WeekDay, Year};
 use crate::utils::StrExt;
 
@@ -114,11 +115,19 @@ impl Date {
 }
 
 impl Date {
-    // TODO: might make this more powerful
-    pub fn formatted(&self, f: &str) -> String {
-        f.replace("{year}", &format!("{:04}", self.year()))
-            .replace("{month}", &format!("{:02}", self.month()))
-            .replace("{day}", &format!("{:02}", self.day()))
+    /// The `{year}`, `{month}` and `{day}` placeholders of the date.
+    #[must_use]
+    pub fn template_context(&self) -> TemplateContext {
+        TemplateContext::new()
+            .with("year", self.year())
+            .with("month", self.month())
+            .with("day", self.day())
+    }
+
+    /// Renders the template with the placeholders of the date, for example
+    /// `{month:02}.toml`.
+    pub fn formatted(&self, template: &str) -> Result<String, TemplateError> {
+        Template::new(template).render(&self.template_context())
     }
 }
 
@@ -503,6 +512,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_formatted() {
+        let date = Date::new(Year::new(2023), Month::March, 5).unwrap();
+
+        assert_eq!(date.formatted("{month:02}.toml"), Ok("03.toml".to_string()));
+        assert_eq!(
+            date.formatted("{year}/{month}/{day:02}.{month:02}.{year:02}"),
+            Ok("2023/3/05.03.23".to_string())
+        );
+        assert!(matches!(
+            date.formatted("{mnth}.toml"),
+            Err(TemplateError::UnknownPlaceholder { .. })
+        ));
+    }
+
     #[must_use]
     fn sort_array<T: Ord, const N: usize>(mut array: [T; N]) -> [T; N] {
         array.sort();
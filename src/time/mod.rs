@@ -64,17 +64,21 @@ impl DurationExt for Duration {
     }
 }
 
+mod clock;
 mod date;
 pub mod holiday;
 mod month;
+mod month_range;
 mod time_span;
 mod time_stamp;
 mod week_day;
 mod working_duration;
 mod year;
 
+pub use clock::*;
 pub use date::*;
 pub use month::*;
+pub use month_range::*;
 pub use time_span::*;
 pub use time_stamp::*;
 pub use week_day::*;
@@ -0,0 +1,113 @@
+use core::fmt;
+use std::str::FromStr;
+
+use crate::time::Date;
+
+/// An inclusive range of months like `2023-04..2023-09`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthRange {
+    start: Date,
+    end: Date,
+}
+
+impl MonthRange {
+    /// Creates a range from the month of `start` until the month of `end`.
+    pub fn new(start: Date, end: Date) -> anyhow::Result<Self> {
+        let start = Date::first_day(start.year(), start.month());
+        let end = Date::first_day(end.year(), end.month());
+
+        if start > end {
+            return Err(anyhow::anyhow!(
+                "the range starts after it ends: {:04}-{:02}..{:04}-{:02}",
+                start.year(),
+                start.month(),
+                end.year(),
+                end.month()
+            ));
+        }
+
+        Ok(Self { start, end })
+    }
+
+    /// Returns the first day of every month in the range.
+    #[must_use]
+    pub fn months(&self) -> Vec<Date> {
+        let mut result = vec![self.start];
+
+        while let Some(last) = result.last().copied().filter(|last| *last < self.end) {
+            result.push(Date::last_day(last.year(), last.month()) + 1);
+        }
+
+        result
+    }
+}
+
+impl FromStr for MonthRange {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (start, end) = string.split_once("..").ok_or_else(|| {
+            anyhow::anyhow!(
+                "\"{}\" is not a valid range of months (YYYY-MM..YYYY-MM)",
+                string
+            )
+        })?;
+
+        Self::new(
+            format!("{}-01", start.trim()).parse()?,
+            format!("{}-01", end.trim()).parse()?,
+        )
+    }
+}
+
+impl fmt::Display for MonthRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}..{:04}-{:02}",
+            self.start.year(),
+            self.start.month(),
+            self.end.year(),
+            self.end.month()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    use crate::time::{Month, Year};
+
+    #[test]
+    fn test_parse_range() {
+        let range: MonthRange = "2022-11..2023-02".parse().unwrap();
+
+        assert_eq!(
+            range.months(),
+            vec![
+                Date::first_day(Year::new(2022), Month::November),
+                Date::first_day(Year::new(2022), Month::December),
+                Date::first_day(Year::new(2023), Month::January),
+                Date::first_day(Year::new(2023), Month::February),
+            ]
+        );
+        assert_eq!(range.to_string(), "2022-11..2023-02");
+    }
+
+    #[test]
+    fn test_single_month() {
+        let range: MonthRange = "2023-04..2023-04".parse().unwrap();
+
+        assert_eq!(range.months().len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert!("2023-09..2023-04".parse::<MonthRange>().is_err());
+        assert!("2023-04".parse::<MonthRange>().is_err());
+        assert!("2023-13..2024-01".parse::<MonthRange>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
+use std::str::FromStr;
 use std::time::Duration;
 
+use anyhow::Context;
 use derive_more::Display;
 
 use crate::time::{TimeStamp, WorkingDuration};
@@ -50,13 +52,50 @@ impl TimeSpan {
     }
 }
 
+impl FromStr for TimeSpan {
+    type Err = anyhow::Error;
+
+    /// Parses a time span in the format `HH:MM-HH:MM`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (start, end) = string
+            .split_once('-')
+            .with_context(|| format!("\"{}\" is not a valid time span (HH:MM-HH:MM)", string))?;
+
+        let start = start.trim().parse::<TimeStamp>()?;
+        let end = end.trim().parse::<TimeStamp>()?;
+
+        if end < start {
+            return Err(anyhow::anyhow!(
+                "the time span \"{}\" ends before it starts",
+                string
+            ));
+        }
+
+        Ok(Self::new(start, end))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
 
-    use crate::working_duration;
+    use crate::{time_stamp, working_duration};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "13:10-14:30".parse::<TimeSpan>().unwrap(),
+            TimeSpan::new(time_stamp!(13:10), time_stamp!(14:30))
+        );
+        assert_eq!(
+            "08:00 - 09:15".parse::<TimeSpan>().unwrap(),
+            TimeSpan::new(time_stamp!(08:00), time_stamp!(09:15))
+        );
+        assert!("14:30-13:10".parse::<TimeSpan>().is_err());
+        assert!("13:10".parse::<TimeSpan>().is_err());
+    }
 
     #[test]
     fn test_overlapping_duration() {
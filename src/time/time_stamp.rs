@@ -95,7 +95,9 @@ impl FromStr for TimeStamp {
     type Err = anyhow::Error;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (hour, minute) = string.split_once(':').unwrap();
+        let (hour, minute) = string
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("\"{}\" is not a valid time (HH:MM)", string))?;
 
         Ok(Self::new(hour.parse()?, minute.parse()?)?)
     }
@@ -1,9 +1,10 @@
 use std::ops::Add;
 use std::str::FromStr;
 
+use derive_more::Display;
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Deserialize, Display)]
 #[serde(try_from = "String")]
 pub enum WeekDay {
     Monday = 1,
@@ -49,6 +50,20 @@ impl WeekDay {
         *self as usize
     }
 
+    /// The two letter abbreviation of the week day, for example `Mo` for `Monday`.
+    #[must_use]
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Monday => "Mo",
+            Self::Tuesday => "Tu",
+            Self::Wednesday => "We",
+            Self::Thursday => "Th",
+            Self::Friday => "Fr",
+            Self::Saturday => "Sa",
+            Self::Sunday => "Su",
+        }
+    }
+
     #[must_use]
     pub const fn add_const(self, days: usize) -> Self {
         Self::week_days()[(self.as_usize() - 1 + days % 7) % 7]
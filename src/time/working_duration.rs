@@ -109,7 +109,9 @@ impl FromStr for WorkingDuration {
     type Err = anyhow::Error;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (hour, minute) = string.split_once(':').unwrap();
+        let (hour, minute) = string
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("\"{}\" is not a valid duration (HH:MM)", string))?;
 
         Ok(Self::new(hour.parse()?, minute.parse()?)?)
     }
@@ -60,10 +60,17 @@ pub fn get_proportions(json_month_file: &MonthFile) -> IndexMap<&str, WorkingDur
 }
 
 #[must_use]
-pub fn make_month_file(global: toml_input::Global, month: toml_input::Month) -> MonthFile {
-    let config = Config::try_from_toml(month, global)
+#[allow(dead_code)]
+pub fn make_config(global: toml_input::Global, month: toml_input::Month) -> Config {
+    Config::try_from_toml(month, global)
         .expect("config should be valid")
-        .build();
+        .build()
+}
+
+#[must_use]
+#[allow(dead_code)]
+pub fn make_month_file(global: toml_input::Global, month: toml_input::Month) -> MonthFile {
+    let config = make_config(global, month);
 
     let json_month_file: MonthFile = serde_json::from_str(
         &config
@@ -95,6 +102,93 @@ pub fn make_global(working_time: WorkingDuration) -> String {
     )
 }
 
+/// Parses the global of [`make_global`] with additional sections.
+#[must_use]
+#[allow(dead_code)]
+pub fn parse_global(working_time: WorkingDuration, sections: &str) -> toml_input::Global {
+    toml::from_str(&(make_global(working_time) + sections)).expect("toml should be valid")
+}
+
+/// A month file of `MENSA` with the sections after `[general]`.
+#[must_use]
+#[allow(dead_code)]
+pub fn make_month(year: usize, month: usize, sections: &str) -> toml_input::Month {
+    toml::from_str(&format!(
+        concat!(
+            "[general]\n",
+            "month = {}\n",
+            "year = {}\n",
+            "department = \"MENSA\"\n",
+            "\n",
+            "{}",
+        ),
+        month, year, sections
+    ))
+    .expect("toml should be valid")
+}
+
+/// Two hours of work every tuesday.
+#[allow(dead_code)]
+pub const REGULAR_WORK: &str = concat!(
+    "[repeating.\"regular work\"]\n",
+    "start = \"08:00\"\n",
+    "end = \"10:00\"\n",
+    "repeats_on = [\"Tuesday\"]\n",
+    "repeats_every = \"week\"\n",
+);
+
+/// Eight hours of work on the 2nd and 3rd, one hour from the previous month
+/// and 30 minutes for the next month.
+#[allow(dead_code)]
+pub const WORK_WITH_TRANSFER: &str = concat!(
+    "[transfer]\n",
+    "prev = \"01:00\"\n",
+    "next = \"00:30\"\n",
+    "\n",
+    "[entries.2]\n",
+    "action = \"work\"\n",
+    "start = \"08:00\"\n",
+    "end = \"12:00\"\n",
+    "\n",
+    "[entries.3]\n",
+    "action = \"work\"\n",
+    "start = \"08:00\"\n",
+    "end = \"12:00\"\n",
+);
+
+/// Vacation on the 10th, starting at 10:00.
+#[allow(dead_code)]
+pub const HOLIDAY: &str = concat!("[holiday]\n", "day = 10\n", "start = \"10:00\"\n");
+
+/// How much vacation is on the time sheet.
+#[must_use]
+#[allow(dead_code)]
+pub fn vacation_of(config: &Config) -> WorkingDuration {
+    config
+        .month()
+        .resolve()
+        .entries()
+        .filter(|(_, entry)| entry.is_vacation())
+        .map(|(_, entry)| entry.work_duration())
+        .sum()
+}
+
+/// Builds the configs of `MENSA` for the `(year, month)`s with
+/// [`WORK_WITH_TRANSFER`].
+#[must_use]
+#[allow(dead_code)]
+pub fn make_worked_configs(global: &toml_input::Global, months: &[(usize, usize)]) -> Vec<Config> {
+    months
+        .iter()
+        .map(|(year, month)| {
+            make_config(
+                global.clone(),
+                make_month(*year, *month, WORK_WITH_TRANSFER),
+            )
+        })
+        .collect()
+}
+
 #[allow(dead_code)]
 pub fn debug_setup() {
     std::env::set_var("RUST_BACKTRACE", "1");
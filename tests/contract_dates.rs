@@ -0,0 +1,122 @@
+//! Tests that nothing is scheduled outside of the contract.
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::Config;
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn global(start_date: &str, end_date: &str) -> Global {
+    let global = common::make_global(working_duration!(10:00))
+        .replace("2009-10-01", start_date)
+        .replace("2239-09-30", end_date);
+
+    toml::from_str(&(global + common::REGULAR_WORK)).expect("toml should be valid")
+}
+
+fn month(entries: &str) -> toml_input::Month {
+    common::make_month(
+        2022,
+        11,
+        &("[dynamic.\"filler\"]\nflex = 1\n\n".to_string() + entries),
+    )
+}
+
+#[test]
+fn test_month_outside_of_contract() {
+    assert!(Config::try_from_toml(month(""), global("2022-12-01", "2023-11-30")).is_err());
+    assert!(Config::try_from_toml(month(""), global("2021-12-01", "2022-10-31")).is_err());
+    assert!(Config::try_from_toml(month(""), global("2022-11-30", "2023-11-30")).is_ok());
+}
+
+#[test]
+fn test_entry_outside_of_contract() {
+    let entry = concat!(
+        "[entries.14]\n",
+        "action = \"meeting\"\n",
+        "start = \"13:00\"\n",
+        "end = \"14:00\"\n",
+    );
+
+    assert!(Config::try_from_toml(month(entry), global("2022-11-15", "2023-11-30")).is_err());
+    assert!(Config::try_from_toml(month(entry), global("2022-11-14", "2023-11-30")).is_ok());
+    assert!(Config::try_from_toml(month(entry), global("2021-11-14", "2022-11-13")).is_err());
+}
+
+#[test]
+fn test_partial_month() {
+    let config = common::make_config(global("2022-11-16", "2023-11-30"), month(""));
+
+    let resolved = config.month().resolve();
+
+    // the repeating events on the 8th and 15th are before the contract starts
+    assert_eq!(
+        resolved
+            .entries()
+            .filter(|(_, entry)| entry.action() == "regular work")
+            .map(|(_, entry)| entry.day())
+            .collect::<Vec<_>>(),
+        vec![22, 29]
+    );
+
+    assert!(resolved.entries().all(|(_, entry)| entry.day() >= 16));
+    // 13 of the 25 workdays are covered by the contract
+    assert_eq!(resolved.total_working_time(), working_duration!(05:12));
+    assert_eq!(
+        config.month().expected_working_duration(),
+        working_duration!(05:12)
+    );
+}
+
+#[test]
+fn test_pro_rating() {
+    let global = |start_date: &str, end_date: &str, pro_rating: &str| -> Global {
+        let global = common::make_global(working_duration!(10:00))
+            .replace("2009-10-01", start_date)
+            .replace("2239-09-30", end_date);
+
+        toml::from_str(&format!(
+            "{}\npro_rating = \"{}\"\n",
+            global.trim_end(),
+            pro_rating
+        ))
+        .expect("toml should be valid")
+    };
+
+    let expected = |global: Global| {
+        let config = common::make_config(global, month(""));
+
+        let global_json: serde_json::Value =
+            serde_json::from_str(&config.to_global_json().unwrap()).unwrap();
+
+        // the scheduler and the generated sheet should agree
+        assert_eq!(
+            global_json["workingTime"],
+            serde_json::Value::String(config.month().expected_working_duration().to_string())
+        );
+
+        config.month().expected_working_duration()
+    };
+
+    // the contract covers the whole month
+    assert_eq!(
+        expected(global("2022-11-01", "2023-11-30", "calendar-days")),
+        working_duration!(10:00)
+    );
+    // 15 of the 30 days are covered by the contract
+    assert_eq!(
+        expected(global("2022-11-16", "2023-11-30", "calendar-days")),
+        working_duration!(05:00)
+    );
+    assert_eq!(
+        expected(global("2021-11-01", "2022-11-15", "calendar-days")),
+        working_duration!(05:00)
+    );
+    // 12 of the 25 workdays are covered by the contract
+    assert_eq!(
+        expected(global("2021-11-01", "2022-11-15", "workdays")),
+        working_duration!(04:48)
+    );
+}
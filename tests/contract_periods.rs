@@ -0,0 +1,72 @@
+//! Tests that the period of a contract that applies to a month is used.
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn global() -> Global {
+    common::parse_global(
+        working_duration!(40:00),
+        concat!(
+            "[[contract.MENSA.periods]]\n",
+            "start_date = 2022-11-15\n",
+            "working_time = \"60:00\"\n",
+            "wage = 13.50\n",
+            "\n",
+            "[[contract.MENSA.periods]]\n",
+            "start_date = 2023-01-01\n",
+            "area = \"ub\"\n",
+        ),
+    )
+}
+
+fn month(year: usize, month: usize) -> toml_input::Month {
+    common::make_month(year, month, common::HOLIDAY)
+}
+
+fn global_json(month: toml_input::Month) -> serde_json::Value {
+    let config = common::make_config(global(), month);
+
+    serde_json::from_str(&config.to_global_json().unwrap()).unwrap()
+}
+
+fn holiday_duration(month: toml_input::Month) -> time_sheet::time::WorkingDuration {
+    common::vacation_of(&common::make_config(global(), month))
+}
+
+#[test]
+fn test_period_before_month() {
+    let json = global_json(month(2022, 10));
+
+    assert_eq!(json["workingTime"], "40:00");
+    assert_eq!(json["wage"], 12.0);
+    assert_eq!(json["workingArea"], "gf");
+    assert_eq!(holiday_duration(month(2022, 10)), working_duration!(03:06));
+}
+
+#[test]
+fn test_period_starts_in_month() {
+    let json = global_json(month(2022, 11));
+
+    assert_eq!(json["workingTime"], "60:00");
+    assert_eq!(json["wage"], 13.5);
+    assert_eq!(json["workingArea"], "gf");
+    assert_eq!(holiday_duration(month(2022, 11)), working_duration!(04:39));
+}
+
+#[test]
+fn test_period_keeps_values_of_previous_periods() {
+    let json = global_json(month(2022, 12));
+
+    assert_eq!(json["workingTime"], "60:00");
+    assert_eq!(json["workingArea"], "gf");
+
+    let json = global_json(month(2023, 1));
+
+    assert_eq!(json["workingTime"], "60:00");
+    assert_eq!(json["wage"], 13.5);
+    assert_eq!(json["workingArea"], "ub");
+}
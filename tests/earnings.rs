@@ -0,0 +1,82 @@
+//! Tests for the earnings report and the warnings about the wage.
+
+use time_sheet::input::toml_input::Global;
+use time_sheet::report::{Earnings, TotalDuration, WageWarning};
+use time_sheet::time::{Month, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+#[test]
+fn test_earnings_include_transfer() {
+    let global = common::parse_global(working_duration!(08:00), "");
+    let earnings = Earnings::from_configs(&common::make_worked_configs(&global, &[(2022, 11)]));
+
+    let month = &earnings.months()[0];
+    // 8 hours of entries, one hour from the previous month and 30 minutes
+    // are transferred to the next month
+    assert_eq!(month.paid(), TotalDuration::from_mins(8 * 60 + 30));
+    assert_eq!(format!("{:.2}", month.earnings()), "102.00");
+    assert_eq!(earnings.warnings(), vec![]);
+}
+
+#[test]
+fn test_earnings_per_year() {
+    let global = common::parse_global(working_duration!(08:00), "");
+    let earnings = Earnings::from_configs(&common::make_worked_configs(
+        &global,
+        &[(2022, 12), (2022, 11)],
+    ));
+
+    assert_eq!(earnings.months()[0].month(), Month::November);
+    assert_eq!(earnings.months()[1].month(), Month::December);
+
+    let years = earnings.years();
+    assert_eq!(years.len(), 1);
+    assert_eq!(years[0].0, Year::new(2022));
+    assert_eq!(years[0].1, TotalDuration::from_mins(17 * 60));
+    assert_eq!(format!("{:.2}", years[0].2), "204.00");
+}
+
+#[test]
+fn test_below_minimum_wage() {
+    let global: Global =
+        toml::from_str(&common::make_global(working_duration!(08:00)).replace("12.00", "9.00"))
+            .expect("toml should be valid");
+    let earnings = Earnings::from_configs(&common::make_worked_configs(&global, &[(2022, 11)]));
+
+    assert_eq!(
+        earnings.warnings(),
+        vec![WageWarning::BelowMinimumWage {
+            department: "MENSA".to_string(),
+            year: Year::new(2022),
+            month: Month::November,
+            wage: 9.00,
+            minimum: 12.00,
+        }]
+    );
+}
+
+#[test]
+fn test_missing_wage() {
+    let global: Global = toml::from_str(
+        &common::make_global(working_duration!(08:00)).replace("wage = 12.00\n", ""),
+    )
+    .expect("toml should be valid");
+    let earnings = Earnings::from_configs(&common::make_worked_configs(
+        &global,
+        &[(2022, 11), (2022, 12)],
+    ));
+
+    // the warning is only reported once
+    assert_eq!(
+        earnings.warnings(),
+        vec![WageWarning::MissingWage {
+            department: "MENSA".to_string(),
+            wage: 12.00,
+        }]
+    );
+    assert_eq!(format!("{:.2}", earnings.months()[0].earnings()), "102.00");
+}
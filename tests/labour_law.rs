@@ -0,0 +1,107 @@
+//! Tests that the working time law is checked across all contracts.
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::{Config, ConfigBuilder};
+use time_sheet::report::{LabourLawCheck, LabourLawViolation};
+use time_sheet::time::{Date, Month, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn global() -> Global {
+    common::parse_global(
+        working_duration!(10:00),
+        concat!(
+            "[contract.IANA]\n",
+            "working_time = \"10:00\"\n",
+            "area = \"ub\"\n",
+            "start_date = 2009-10-01\n",
+            "end_date = 2239-09-30\n",
+        ),
+    )
+}
+
+fn configs(sections: &str) -> Vec<Config> {
+    let month: toml_input::Month = toml::from_str(
+        &(concat!("[general]\n", "month = 11\n", "year = 2022\n", "\n",).to_string() + sections),
+    )
+    .expect("toml should be valid");
+
+    ConfigBuilder::build_all(Config::try_all_from_toml(month, global()).unwrap())
+        .expect("configs should be valid")
+}
+
+fn date(day: usize) -> Date {
+    Date::new(Year::new(2022), Month::November, day).unwrap()
+}
+
+#[test]
+fn test_rest_period_across_contracts() {
+    let check = LabourLawCheck::new(&configs(concat!(
+        "[department.IANA.entries.14]\n",
+        "action = \"exercise\"\n",
+        "start = \"14:00\"\n",
+        "end = \"22:00\"\n",
+        "pause = \"00:30\"\n",
+        "\n",
+        "[department.MENSA.entries.15]\n",
+        "action = \"meeting\"\n",
+        "start = \"08:00\"\n",
+        "end = \"10:00\"\n",
+    )));
+
+    assert_eq!(
+        check.violations(),
+        &[LabourLawViolation::RestPeriod {
+            previous: date(14),
+            date: date(15),
+            rest: working_duration!(10:00),
+            required: working_duration!(11:00),
+        }]
+    );
+}
+
+#[test]
+fn test_pause_across_contracts() {
+    // each contract on its own does not require a pause
+    let check = LabourLawCheck::new(&configs(concat!(
+        "[department.IANA.entries.14]\n",
+        "action = \"exercise\"\n",
+        "start = \"08:00\"\n",
+        "end = \"12:00\"\n",
+        "\n",
+        "[department.MENSA.entries.14]\n",
+        "action = \"meeting\"\n",
+        "start = \"12:00\"\n",
+        "end = \"15:00\"\n",
+    )));
+
+    assert_eq!(
+        check.violations(),
+        &[LabourLawViolation::Pause {
+            date: date(14),
+            worked: working_duration!(07:00),
+            pause: working_duration!(00:00),
+            required: working_duration!(00:30),
+        }]
+    );
+}
+
+#[test]
+fn test_no_violations() {
+    let check = LabourLawCheck::new(&configs(concat!(
+        "[department.IANA.entries.14]\n",
+        "action = \"exercise\"\n",
+        "start = \"08:00\"\n",
+        "end = \"12:00\"\n",
+        "\n",
+        "[department.MENSA.entries.14]\n",
+        "action = \"meeting\"\n",
+        "start = \"12:30\"\n",
+        "end = \"15:30\"\n",
+    )));
+
+    assert!(check.is_ok());
+}
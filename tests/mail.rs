@@ -0,0 +1,174 @@
+//! Tests for the email that is sent with the time sheet.
+#![cfg(feature = "lettre")]
+
+use std::fs;
+
+use time_sheet::email::{build_email, recipients_of};
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::Config;
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+const MAIL: &str = concat!(
+    "[mail]\n",
+    "from = { name = \"John Smith\", email = \"john@example.com\" }\n",
+    "bcc = [{ name = \"John Smith\", email = \"john@example.com\" }]\n",
+    "\n",
+    "[mail.body]\n",
+    "text = \"{name} ({staff_id}), {department} {year}-{month}: {total} ({transfer})\"\n",
+    "\n",
+    "[mail.smtp]\n",
+    "url = \"smtp.example.com\"\n",
+    "username = \"john\"\n",
+    "password = \"secret\"\n",
+);
+
+fn month() -> toml_input::Month {
+    common::make_month(
+        2022,
+        11,
+        concat!(
+            "[transfer]\n",
+            "prev = \"01:00\"\n",
+            "next = \"00:00\"\n",
+            "\n",
+            "[entries.2]\n",
+            "action = \"work\"\n",
+            "start = \"08:00\"\n",
+            "end = \"12:00\"\n",
+        ),
+    )
+}
+
+#[test]
+fn test_body_placeholders() {
+    let config = common::make_config(
+        common::parse_global(working_duration!(10:00), MAIL),
+        month(),
+    );
+    let mail = config.mail().expect("mail should be configured");
+
+    assert_eq!(mail.bcc().len(), 1);
+    assert_eq!(
+        mail.body()
+            .expect("body should be configured")
+            .text(&config.template_context())
+            .expect("body should be valid"),
+        "John Smith (1234567), MENSA 2022-11: 04:00 (-01:00)"
+    );
+}
+
+#[test]
+fn test_build_email() {
+    let global = common::parse_global(working_duration!(10:00), MAIL);
+
+    let dir = tempfile::tempdir().expect("should be able to create a temporary directory");
+    let output = dir.path().join("11.pdf");
+    fs::write(&output, b"%PDF-1.5").expect("should be able to write the pdf");
+
+    let mut builder = Config::try_from_toml(month(), global).expect("config should be valid");
+    builder.output(&output);
+    let config = builder.build();
+
+    let email = build_email(
+        &config,
+        Some("office@example.com"),
+        "John Smith {year:02}-{month:02}",
+    )
+    .expect("email should be valid");
+    let formatted = String::from_utf8(email.formatted()).expect("email should be utf-8");
+
+    assert!(formatted.contains("From: \"John Smith\" <john@example.com>\r\n"));
+    assert!(formatted.contains("To: office@example.com\r\n"));
+    assert!(formatted.contains("Subject: John Smith 22-11\r\n"));
+    assert!(formatted.contains("John Smith (1234567), MENSA 2022-11: 04:00 (-01:00)"));
+    assert!(formatted.contains("filename=\"11.pdf\""));
+}
+
+fn recipient_headers_of(global: &str) -> String {
+    let global: Global = toml::from_str(global).expect("toml should be valid");
+
+    let dir = tempfile::tempdir().expect("should be able to create a temporary directory");
+    let output = dir.path().join("11.pdf");
+    fs::write(&output, b"%PDF-1.5").expect("should be able to write the pdf");
+
+    let mut builder = Config::try_from_toml(month(), global).expect("config should be valid");
+    builder.output(&output);
+
+    let email = build_email(&builder.build(), None, "time sheet").expect("email should be valid");
+    let formatted = String::from_utf8(email.formatted()).expect("email should be utf-8");
+
+    formatted
+        .lines()
+        .filter(|line| line.starts_with("To: ") || line.starts_with("Cc: "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_recipients() {
+    let global = common::make_global(working_duration!(10:00))
+        + &MAIL.replace(
+            "[mail]\n",
+            "[mail]\nrecipients = [{ name = \"Office\", email = \"office@example.com\" }]\n",
+        );
+
+    // without recipients in the contract, the ones of the mail config are used
+    assert_eq!(
+        recipient_headers_of(&global),
+        "To: Office <office@example.com>"
+    );
+
+    let global = global.replace(
+        "end_date = 2239-09-30\n",
+        concat!(
+            "end_date = 2239-09-30\n",
+            "recipients = [{ name = \"Mensa\", email = \"mensa@example.com\" }]\n",
+            "cc = [{ name = \"Boss\", email = \"boss@example.com\" }]\n",
+        ),
+    );
+
+    assert_eq!(
+        recipient_headers_of(&global),
+        "To: Mensa <mensa@example.com>\nCc: Boss <boss@example.com>"
+    );
+}
+
+#[test]
+fn test_recipients_without_bcc() {
+    let global = common::parse_global(working_duration!(10:00), MAIL);
+
+    let dir = tempfile::tempdir().expect("should be able to create a temporary directory");
+    let output = dir.path().join("11.pdf");
+    fs::write(&output, b"%PDF-1.5").expect("should be able to write the pdf");
+
+    let mut builder = Config::try_from_toml(month(), global).expect("config should be valid");
+    builder.output(&output);
+
+    let email = build_email(
+        &builder.build(),
+        Some("Office <office@example.com>"),
+        "time sheet",
+    )
+    .expect("email should be valid");
+
+    // john@example.com is in BCC
+    assert_eq!(email.envelope().to().len(), 2);
+    assert_eq!(
+        recipients_of(&email),
+        vec!["Office <office@example.com>".to_string()]
+    );
+}
+
+#[test]
+fn test_missing_recipients() {
+    let config = common::make_config(
+        common::parse_global(working_duration!(10:00), MAIL),
+        month(),
+    );
+
+    assert!(build_email(&config, None, "time sheet").is_err());
+}
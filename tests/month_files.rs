@@ -0,0 +1,47 @@
+//! Tests that only the month files of a directory are found.
+
+use std::fs;
+
+use time_sheet::input::month_files_in;
+use time_sheet::session::Session;
+use time_sheet::{date, time_stamp, working_duration};
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+#[test]
+fn test_skips_other_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = |name: &str| dunce::canonicalize(dir.path()).unwrap().join(name);
+
+    fs::write(
+        path("global.toml"),
+        common::make_global(working_duration!(10:00)),
+    )
+    .unwrap();
+    fs::write(path("12.toml"), "[general]\nmonth = 12\nyear = 2022\n").unwrap();
+    fs::write(path("11.toml"), "[general]\nmonth = 11\nyear = 2022\n").unwrap();
+    fs::write(path("notes.toml"), "todo = [\"Korrektur\"]\n").unwrap();
+    fs::write(path("11.pdf"), "").unwrap();
+    Session::new("Korrektur", None, date!(2022:12:13), time_stamp!(13:12))
+        .save(path(".time-sheet-session.toml"))
+        .unwrap();
+
+    let month_files = month_files_in(dir.path(), path("global.toml")).unwrap();
+
+    assert_eq!(
+        month_files
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>(),
+        vec![path("11.toml"), path("12.toml")]
+    );
+    assert_eq!(
+        month_files
+            .iter()
+            .map(|(_, month)| month.general().month().as_usize())
+            .collect::<Vec<_>>(),
+        vec![11, 12]
+    );
+}
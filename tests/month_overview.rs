@@ -0,0 +1,75 @@
+//! Tests that the resolved month remembers where each entry comes from
+//! and that the overview shows them.
+
+use time_sheet::input::{Config, EntryOrigin};
+use time_sheet::report::MonthOverview;
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn config() -> Config {
+    common::make_config(
+        common::parse_global(working_duration!(20:00), common::REGULAR_WORK),
+        common::make_month(
+            2022,
+            11,
+            concat!(
+                "[absence.08]\n",
+                "start = \"08:00\"\n",
+                "end = \"12:00\"\n",
+                "\n",
+                "[entries.03]\n",
+                "action = \"meeting\"\n",
+                "start = \"13:00\"\n",
+                "end = \"14:00\"\n",
+                "\n",
+                "[dynamic.\"filler\"]\n",
+                "flex = 1\n",
+            ),
+        ),
+    )
+}
+
+#[test]
+fn test_entry_origins() {
+    let config = config();
+    let resolved = config.month().resolve();
+
+    let origin_of = |action: &str| {
+        resolved
+            .entries()
+            .filter(|(_, entry)| entry.action() == action)
+            .map(|(origin, _)| origin)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(origin_of("meeting"), vec![EntryOrigin::Explicit]);
+    // the 1st is a holiday and the repeating event on the 8th is skipped
+    // because of the absence
+    assert_eq!(origin_of("regular work"), vec![EntryOrigin::Repeating; 3]);
+    assert!(!origin_of("filler").is_empty());
+    assert!(origin_of("filler")
+        .into_iter()
+        .all(|origin| origin == EntryOrigin::Dynamic));
+
+    assert_eq!(resolved.total_working_time(), working_duration!(20:00));
+}
+
+#[test]
+fn test_overview() {
+    let config = config();
+    let overview = MonthOverview::new(&config).to_string();
+
+    assert!(overview.starts_with("2022-11 (MENSA)\n"));
+    assert!(overview.contains("Week 1: 2022-11-01 - 2022-11-06, capacity "));
+    assert!(overview.contains("Week 5: 2022-11-28 - 2022-11-30, capacity "));
+    assert!(overview.contains("Th 2022-11-03  13:00 - 14:00"));
+    assert!(overview.contains("Tu 2022-11-08  08:00 - 12:00"));
+    assert!(overview.contains("absence"));
+    assert!(overview.contains("expected: 20:00\n"));
+    assert!(overview.contains("worked: 20:00\n"));
+    assert!(overview.contains("transfer on the time sheet: +00:00\n"));
+    assert!(overview.ends_with("computed balance (not on the time sheet): +00:00\n"));
+}
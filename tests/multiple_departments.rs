@@ -0,0 +1,208 @@
+//! Tests for month files with sections for multiple departments.
+
+use std::path::Path;
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::{Config, ConfigBuilder, EntryConflict};
+use time_sheet::time::{Date, Month, WorkingDuration, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn global_with(sections: &str) -> Global {
+    common::parse_global(
+        working_duration!(20:00),
+        &(concat!(
+            "[contract.IANA]\n",
+            "working_time = \"20:00\"\n",
+            "area = \"ub\"\n",
+            "start_date = 2009-10-01\n",
+            "end_date = 2239-09-30\n",
+            "\n",
+        )
+        .to_string()
+            + sections),
+    )
+}
+
+fn global() -> Global {
+    global_with("")
+}
+
+fn month(sections: &str) -> toml_input::Month {
+    toml::from_str(
+        &(concat!(
+            "[general]\n",
+            "month = 11\n",
+            "year = 2022\n",
+            "\n",
+            "[absence.21]\n",
+            "start = \"00:00\"\n",
+            "end = \"23:59\"\n",
+            "\n",
+        )
+        .to_string()
+            + sections),
+    )
+    .expect("toml should be valid")
+}
+
+const SECTIONS: &str = concat!(
+    "[department.MENSA.entries.14]\n",
+    "action = \"meeting\"\n",
+    "start = \"08:00\"\n",
+    "end = \"12:00\"\n",
+    "\n",
+    "[department.MENSA.dynamic.\"filler\"]\n",
+    "flex = 1\n",
+    "\n",
+    "[department.IANA.entries.15]\n",
+    "action = \"exercise\"\n",
+    "start = \"10:00\"\n",
+    "end = \"14:00\"\n",
+    "\n",
+    "[department.IANA.dynamic.\"other filler\"]\n",
+    "flex = 1\n",
+);
+
+fn build_all(month: toml_input::Month) -> anyhow::Result<Vec<Config>> {
+    ConfigBuilder::build_all(Config::try_all_from_toml(month, global())?)
+}
+
+#[test]
+fn test_one_config_per_department() {
+    let configs = build_all(month(SECTIONS)).expect("configs should be valid");
+
+    assert_eq!(
+        configs
+            .iter()
+            .map(|config| (config.department(), config.output()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("MENSA", Path::new("2022-11-MENSA.pdf")),
+            ("IANA", Path::new("2022-11-IANA.pdf")),
+        ]
+    );
+
+    for config in &configs {
+        assert_eq!(
+            config.month().resolve().total_working_time(),
+            working_duration!(20:00)
+        );
+    }
+}
+
+#[test]
+fn test_limits_apply_to_all_departments() {
+    let configs = build_all(month(SECTIONS)).expect("configs should be valid");
+    let resolved = configs
+        .iter()
+        .map(|config| config.month().resolve())
+        .collect::<Vec<_>>();
+
+    for date in Year::new(2022).days_in(Month::November) {
+        let entries = resolved
+            .iter()
+            .flat_map(|month| month.entries_on_day(date))
+            .map(|(_, entry)| entry)
+            .collect::<Vec<_>>();
+
+        let total = entries
+            .iter()
+            .map(|entry| entry.work_duration())
+            .sum::<WorkingDuration>();
+
+        assert!(
+            total <= working_duration!(08:00),
+            "worked {} on {}",
+            total,
+            date
+        );
+
+        if date == Date::new(Year::new(2022), Month::November, 21).unwrap() {
+            assert!(entries.is_empty(), "absences should be shared");
+        }
+    }
+}
+
+#[test]
+fn test_overlap_between_departments() {
+    let sections = SECTIONS.to_string()
+        + concat!(
+            "\n",
+            "[department.IANA.entries.14]\n",
+            "action = \"exercise\"\n",
+            "start = \"11:00\"\n",
+            "end = \"13:00\"\n",
+        );
+
+    assert!(build_all(month(&sections)).is_err());
+}
+
+#[test]
+fn test_entries_without_department() {
+    let sections = SECTIONS.to_string()
+        + concat!(
+            "\n",
+            "[entries.16]\n",
+            "action = \"exercise\"\n",
+            "start = \"11:00\"\n",
+            "end = \"13:00\"\n",
+        );
+
+    assert!(build_all(month(&sections)).is_err());
+    // a month file with multiple departments can not be used for a single config
+    assert!(Config::try_from_toml(month(SECTIONS), global()).is_err());
+}
+
+/// A repeating event of `IANA` from 10:00 to 12:00.
+fn tutorial(repeats_on: &str) -> Global {
+    global_with(&format!(
+        concat!(
+            "[repeating.\"tutorial\"]\n",
+            "start = \"10:00\"\n",
+            "end = \"12:00\"\n",
+            "repeats_on = [\"{}\"]\n",
+            "repeats_every = \"week\"\n",
+            "department = \"IANA\"\n",
+        ),
+        repeats_on
+    ))
+}
+
+#[test]
+fn test_repeating_event_of_later_department() {
+    // the filler of MENSA would otherwise be scheduled at the time of the tutorial
+    let configs = ConfigBuilder::build_all(
+        Config::try_all_from_toml(month(SECTIONS), tutorial("Wednesday"))
+            .expect("month should be valid"),
+    )
+    .expect("configs should be valid");
+
+    let tutorials = configs[1]
+        .month()
+        .resolve()
+        .entries()
+        .filter(|(_, entry)| entry.action() == "tutorial")
+        .map(|(_, entry)| entry.day())
+        .collect::<Vec<_>>();
+
+    assert_eq!(tutorials, vec![2, 9, 16, 23, 30]);
+}
+
+#[test]
+fn test_repeating_event_overlaps_other_department() {
+    // the meeting of MENSA on monday the 14th is from 08:00 to 12:00
+    let error = ConfigBuilder::build_all(
+        Config::try_all_from_toml(month(SECTIONS), tutorial("Monday"))
+            .expect("month should be valid"),
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<EntryConflict>(),
+        Some(EntryConflict::Overlap { action, .. }) if action == "meeting"
+    ));
+}
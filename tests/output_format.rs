@@ -0,0 +1,59 @@
+//! Tests for the placeholders in the file names of the global config.
+
+use std::path::Path;
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::Config;
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn global(config: &str) -> Global {
+    common::parse_global(
+        working_duration!(10:00),
+        &("[config]\n".to_string() + config),
+    )
+}
+
+fn month() -> toml_input::Month {
+    common::make_month(2023, 3, "")
+}
+
+#[test]
+fn test_output_format() {
+    let config = Config::try_from_toml(
+        month(),
+        global(concat!(
+            "output_format = \"{name} {staff_id} {department} {month:de} {year:02}\"\n",
+            "preserve_dir = \"latex/{year}-{month:02}\"\n",
+        )),
+    )
+    .expect("config should be valid")
+    .build();
+
+    assert_eq!(
+        config.output(),
+        Path::new("John Smith 1234567 MENSA März 23.pdf")
+    );
+    assert_eq!(config.preserve_dir(), Some(Path::new("latex/2023-03")));
+}
+
+#[test]
+fn test_default_output_format() {
+    let config = Config::try_from_toml(month(), global(""))
+        .expect("config should be valid")
+        .build();
+
+    assert_eq!(config.output(), Path::new("2023-03.pdf"));
+}
+
+#[test]
+fn test_unknown_placeholder() {
+    let error = Config::try_from_toml(month(), global("output_format = \"{year}-{mont}\"\n"))
+        .err()
+        .expect("the placeholder should be unknown");
+
+    assert!(format!("{:#}", error).contains("unknown placeholder `{mont}`"));
+}
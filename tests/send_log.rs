@@ -0,0 +1,78 @@
+//! Tests for the record of the sent time sheets.
+
+use time_sheet::input::toml_input::Global;
+use time_sheet::input::Config;
+use time_sheet::send_log::{self, SendLog, SentMail};
+use time_sheet::time::{self, Month, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+const WORK: &str = concat!(
+    "[entries.2]\n",
+    "action = \"work\"\n",
+    "start = \"08:00\"\n",
+    "end = \"12:00\"\n",
+);
+
+fn global(start_date: &str) -> Global {
+    toml::from_str(&common::make_global(working_duration!(10:00)).replace("2009-10-01", start_date))
+        .expect("toml should be valid")
+}
+
+fn config(global: Global, entries: &str) -> Config {
+    common::make_config(global, common::make_month(2022, 11, entries))
+}
+
+#[test]
+fn test_find_last_sent() {
+    let config = config(global("2009-10-01"), WORK);
+    let recipients = vec!["Office <office@example.com>".to_string()];
+
+    let mut log = SendLog::default();
+    assert_eq!(log.find(&config), None);
+
+    log.record(SentMail::new(&config, recipients.clone(), time::now(), "a"));
+    log.record(SentMail::new(&config, recipients, time::now(), "b"));
+
+    let sent = log.find(&config).expect("the month should have been sent");
+    assert_eq!(sent.input_hash(), "b");
+    assert_eq!(
+        (sent.year(), sent.month()),
+        (Year::new(2022), Month::November)
+    );
+}
+
+#[test]
+fn test_find_other_contract() {
+    let recipients = vec!["Office <office@example.com>".to_string()];
+
+    let mut log = SendLog::default();
+    log.record(SentMail::new(
+        &config(global("2009-10-01"), WORK),
+        recipients,
+        time::now(),
+        "a",
+    ));
+
+    // a new contract of the same department
+    assert_eq!(log.find(&config(global("2022-11-01"), WORK)), None);
+}
+
+#[test]
+fn test_hash_input() {
+    let hash = |global, entries| {
+        send_log::hash_input(&config(global, entries)).expect("config should be serializable")
+    };
+
+    assert_eq!(
+        hash(global("2009-10-01"), WORK),
+        hash(global("2009-10-01"), WORK)
+    );
+    assert_ne!(
+        hash(global("2009-10-01"), WORK),
+        hash(global("2009-10-01"), &WORK.replace("12:00", "13:00"))
+    );
+}
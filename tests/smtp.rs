@@ -0,0 +1,156 @@
+//! Tests the smtp transport against a local stand-in for an smtp server.
+#![cfg(feature = "lettre")]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use lettre::Transport;
+
+use time_sheet::email::build_email;
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::Config;
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+/// What the server received in a single mail transaction.
+#[derive(Debug, Default)]
+struct Received {
+    commands: Vec<String>,
+    data: String,
+}
+
+/// Answers like an smtp relay without authentication and sends every received
+/// mail through the channel.
+fn serve(stream: TcpStream, sender: &mpsc::Sender<Received>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut received = Received::default();
+
+    writer.write_all(b"220 localhost ESMTP\r\n")?;
+
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        let command = line.trim_end().to_string();
+        line.clear();
+
+        let verb = command
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        received.commands.push(command);
+
+        match verb.as_str() {
+            "EHLO" | "HELO" => writer.write_all(b"250 localhost\r\n")?,
+            "DATA" => {
+                writer.write_all(b"354 end data with <CR><LF>.<CR><LF>\r\n")?;
+
+                while reader.read_line(&mut line)? > 0 {
+                    if line == ".\r\n" {
+                        break;
+                    }
+
+                    received.data.push_str(&line);
+                    line.clear();
+                }
+                line.clear();
+
+                writer.write_all(b"250 ok\r\n")?;
+                let _ = sender.send(core::mem::take(&mut received));
+            }
+            "QUIT" => {
+                writer.write_all(b"221 bye\r\n")?;
+                break;
+            }
+            _ => writer.write_all(b"250 ok\r\n")?,
+        }
+    }
+
+    Ok(())
+}
+
+fn start_server() -> (u16, mpsc::Receiver<Received>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("should be able to bind a port");
+    let port = listener.local_addr().unwrap().port();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = serve(stream, &sender);
+        }
+    });
+
+    (port, receiver)
+}
+
+#[test]
+fn test_send_without_tls_and_credentials() {
+    let (port, receiver) = start_server();
+
+    let global: Global = toml::from_str(&format!(
+        concat!(
+            "{}",
+            "[mail]\n",
+            "from = {{ name = \"John Smith\", email = \"john@example.com\" }}\n",
+            "recipients = [{{ name = \"Office\", email = \"office@example.com\" }}]\n",
+            "\n",
+            "[mail.smtp]\n",
+            "url = \"127.0.0.1\"\n",
+            "port = {}\n",
+            "tls = \"none\"\n",
+            "timeout = 5\n",
+        ),
+        common::make_global(working_duration!(10:00)),
+        port
+    ))
+    .expect("toml should be valid");
+    let month: toml_input::Month = toml::from_str(concat!(
+        "[general]\n",
+        "month = 11\n",
+        "year = 2022\n",
+        "department = \"MENSA\"\n",
+    ))
+    .expect("toml should be valid");
+
+    let dir = tempfile::tempdir().expect("should be able to create a temporary directory");
+    let output = dir.path().join("11.pdf");
+    fs::write(&output, b"%PDF-1.5").expect("should be able to write the pdf");
+
+    let mut builder = Config::try_from_toml(month, global).expect("config should be valid");
+    builder.output(&output);
+    let config = builder.build();
+
+    let email = build_email(&config, None, "time sheet {month:02}").expect("email should be valid");
+    let transport = config
+        .mail()
+        .expect("mail should be configured")
+        .to_transport()
+        .expect("transport should be valid");
+
+    transport.send(&email).expect("email should be sent");
+
+    let received = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("the server should receive the email");
+
+    assert_eq!(
+        received
+            .commands
+            .iter()
+            .filter(|command| command.starts_with("MAIL") || command.starts_with("RCPT"))
+            .collect::<Vec<_>>(),
+        vec![
+            "MAIL FROM:<john@example.com>",
+            "RCPT TO:<office@example.com>"
+        ]
+    );
+    assert!(received.data.contains("Subject: time sheet 11\r\n"));
+    assert!(received.data.contains("filename=\"11.pdf\""));
+}
@@ -0,0 +1,46 @@
+//! Tests that the statistics sum up the time of multiple months.
+
+use time_sheet::input::Config;
+use time_sheet::report::{Statistics, TotalDuration};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn config(month: usize) -> Config {
+    common::make_config(
+        common::parse_global(working_duration!(10:00), common::REGULAR_WORK),
+        common::make_month(2022, month, "[dynamic.\"filler\"]\nflex = 1\n"),
+    )
+}
+
+#[test]
+fn test_statistics() {
+    // november 2022 has 4 tuesdays (without the 1st, which is a holiday)
+    // and december 2022 has 4 tuesdays
+    let statistics = Statistics::from_configs(&[config(11), config(12)]);
+
+    assert_eq!(statistics.total(), TotalDuration::from_mins(20 * 60));
+    assert_eq!(statistics.fixed(), TotalDuration::from_mins(16 * 60));
+    assert_eq!(statistics.dynamic(), TotalDuration::from_mins(4 * 60));
+
+    assert_eq!(
+        statistics
+            .actions()
+            .iter()
+            .map(|entry| (entry.name(), entry.duration()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("regular work", TotalDuration::from_mins(16 * 60)),
+            ("filler", TotalDuration::from_mins(4 * 60)),
+        ]
+    );
+    assert_eq!(statistics.departments().len(), 1);
+    assert_eq!(statistics.week_days()[1].name(), "Tuesday");
+    assert!(statistics.week_days()[1].duration() >= TotalDuration::from_mins(16 * 60));
+
+    let json: serde_json::Value = serde_json::from_str(&statistics.to_json().unwrap()).unwrap();
+    assert_eq!(json["total"], "20:00");
+    assert_eq!(json["actions"][0]["name"], "regular work");
+}
@@ -0,0 +1,83 @@
+//! Tests the progress of a month against the expected working time.
+
+use time_sheet::input::Config;
+use time_sheet::report::{Distribution, MonthStatus};
+use time_sheet::time::WorkingDuration;
+use time_sheet::{date, transfer, working_duration};
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn config() -> Config {
+    common::make_config(
+        common::parse_global(working_duration!(30:00), common::REGULAR_WORK),
+        common::make_month(
+            2022,
+            11,
+            concat!(
+                "[entries.03]\n",
+                "action = \"meeting\"\n",
+                "start = \"13:00\"\n",
+                "end = \"16:00\"\n",
+                "\n",
+                "[dynamic.\"filler\"]\n",
+                "flex = 1\n",
+            ),
+        ),
+    )
+}
+
+#[test]
+fn test_linear_status() {
+    let config = config();
+    // 30 days in november, 1 hour per day
+    let status = MonthStatus::new(&config, date!(2022:11:15), Distribution::Linear);
+
+    // 03:00 on the 3rd and 02:00 on the 8th and 15th
+    assert_eq!(status.worked(), working_duration!(07:00));
+    assert_eq!(status.expected(), working_duration!(15:00));
+    assert_eq!(status.difference(), transfer!(-08:00));
+    assert_eq!(status.remaining(), working_duration!(23:00));
+
+    let weeks = status.remaining_weeks();
+    assert_eq!(
+        weeks
+            .iter()
+            .map(|week| (week.week_number(), week.dates()))
+            .collect::<Vec<_>>(),
+        vec![
+            (3, date!(2022:11:16)..=date!(2022:11:20)),
+            (4, date!(2022:11:21)..=date!(2022:11:27)),
+            (5, date!(2022:11:28)..=date!(2022:11:30)),
+        ]
+    );
+    assert_eq!(
+        weeks
+            .iter()
+            .map(|week| week.target())
+            .sum::<WorkingDuration>(),
+        working_duration!(23:00)
+    );
+    assert_eq!(weeks[1].planned(), working_duration!(02:00));
+    assert_eq!(
+        weeks[1].open(),
+        weeks[1].target() - working_duration!(02:00)
+    );
+}
+
+#[test]
+fn test_workday_status() {
+    let config = config();
+    let status = MonthStatus::new(&config, date!(2022:11:30), Distribution::Workdays);
+
+    // the whole month has passed
+    assert_eq!(status.expected(), working_duration!(30:00));
+    assert!(status.remaining_weeks().is_empty());
+
+    // dates outside of the month are clamped
+    let status = MonthStatus::new(&config, date!(2022:10:01), Distribution::Workdays);
+    assert_eq!(status.date(), date!(2022:11:01));
+    // the 1st of november is a holiday
+    assert_eq!(status.expected(), working_duration!(00:00));
+}
@@ -3,6 +3,7 @@
 
 use time_sheet::input::json_input::{Entry, MonthFile};
 use time_sheet::input::toml_input::{self, Global};
+use time_sheet::input::{Config, Transfer};
 use time_sheet::time::{Month, Year};
 use time_sheet::{time_stamp, transfer, working_duration};
 
@@ -204,3 +205,42 @@ fn test_transfer_previous_and_next_month() {
         )
     );
 }
+
+#[test]
+fn test_chain_transfer() {
+    let global = common::parse_global(working_duration!(10:00), "");
+
+    let previous = Config::try_from_toml(
+        common::make_month(2022, 10, "[transfer]\nprev = \"00:00\"\nnext = \"02:00\"\n"),
+        global.clone(),
+    )
+    .expect("config should be valid")
+    .build();
+
+    // the transfer to the next month is taken over
+    let mut builder = Config::try_from_toml(common::make_month(2022, 11, ""), global.clone())
+        .expect("config should be valid");
+    builder.chain_transfer(&previous);
+    assert_eq!(
+        builder.build().month().transfer(),
+        Transfer::new(working_duration!(02:00), working_duration!(00:00))
+    );
+
+    // a declared transfer is kept
+    let mut builder = Config::try_from_toml(
+        common::make_month(2022, 11, "[transfer]\nprev = \"01:00\"\nnext = \"00:00\"\n"),
+        global.clone(),
+    )
+    .expect("config should be valid");
+    builder.chain_transfer(&previous);
+    assert_eq!(
+        builder.build().month().transfer(),
+        Transfer::new(working_duration!(01:00), working_duration!(00:00))
+    );
+
+    // only the directly following month takes over the transfer
+    let mut builder = Config::try_from_toml(common::make_month(2022, 12, ""), global)
+        .expect("config should be valid");
+    builder.chain_transfer(&previous);
+    assert_eq!(builder.build().month().transfer(), Transfer::default());
+}
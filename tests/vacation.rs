@@ -0,0 +1,154 @@
+//! Tests for the vacation entitlement of a contract.
+
+use time_sheet::input::toml_input::{self, Global};
+use time_sheet::report::{VacationLedger, VacationState};
+use time_sheet::time::{Month, WorkingDuration, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn vacation_time(sections: &str) -> WorkingDuration {
+    common::vacation_of(&common::make_config(
+        common::parse_global(working_duration!(40:00), sections),
+        common::make_month(2022, 11, common::HOLIDAY),
+    ))
+}
+
+#[test]
+fn test_default_vacation() {
+    assert_eq!(vacation_time(""), working_duration!(03:06));
+}
+
+#[test]
+fn test_custom_vacation() {
+    let vacation = concat!(
+        "[contract.MENSA.vacation]\n",
+        "days_per_year = 30\n",
+        "hours_per_day = 7.8\n",
+        "maximum_hours_per_month = 167.4\n",
+    );
+
+    assert_eq!(vacation_time(vacation), working_duration!(04:40));
+}
+
+#[test]
+fn test_invalid_vacation() {
+    let global = common::make_global(working_duration!(40:00))
+        + concat!(
+            "[contract.MENSA.vacation]\n",
+            "maximum_hours_per_month = 0\n",
+        );
+
+    let error = toml::from_str::<Global>(&global)
+        .expect_err("the vacation should be invalid")
+        .to_string();
+    assert!(error.contains("`maximum_hours_per_month` has to be at least one minute"));
+}
+
+#[test]
+fn test_partial_vacation() {
+    // the other values are the defaults
+    assert_eq!(
+        vacation_time("[contract.MENSA.vacation]\ndays_per_year = 40\n"),
+        working_duration!(06:12)
+    );
+}
+
+fn month_file(department: &str, month: usize, holiday_months: Option<usize>) -> toml_input::Month {
+    let mut result = format!(
+        "[general]\nmonth = {}\nyear = 2022\ndepartment = \"{}\"\n",
+        month, department
+    );
+
+    if let Some(months) = holiday_months {
+        result += &format!("[holiday]\nday = 10\nmonths = {}\n", months);
+    }
+
+    toml::from_str(&result).expect("toml should be valid")
+}
+
+fn ledger_global() -> Global {
+    common::parse_global(
+        working_duration!(40:00),
+        concat!(
+            "[contract.IANA]\n",
+            "working_time = \"20:00\"\n",
+            "area = \"ub\"\n",
+            "start_date = 2022-07-01\n",
+            "end_date = 2023-06-30\n",
+        ),
+    )
+}
+
+#[test]
+fn test_vacation_ledger() {
+    let months = [
+        month_file("MENSA", 1, Some(1)),
+        month_file("MENSA", 2, None),
+        month_file("MENSA", 3, Some(3)),
+        month_file("IANA", 7, None),
+        month_file("IANA", 8, None),
+        // other years are ignored
+        toml::from_str(concat!(
+            "[general]\n",
+            "month = 1\n",
+            "year = 2023\n",
+            "department = \"MENSA\"\n",
+            "[holiday]\n",
+            "day = 10\n",
+        ))
+        .unwrap(),
+    ];
+
+    let ledger = VacationLedger::new(&ledger_global(), Year::new(2022), &months)
+        .expect("ledger should be valid");
+
+    let [mensa, iana] = ledger.accounts() else {
+        panic!("expected two accounts");
+    };
+
+    assert_eq!(mensa.entitled_months(), 12);
+    assert_eq!(mensa.taken_months(), 4);
+    assert_eq!(mensa.state(), VacationState::OnTrack);
+    assert_eq!(mensa.suggestion().len(), 9);
+    assert_eq!(
+        mensa
+            .suggestion()
+            .iter()
+            .map(|(_, months)| months)
+            .sum::<usize>(),
+        8
+    );
+
+    assert_eq!(iana.entitled_months(), 6);
+    assert_eq!(iana.taken_months(), 0);
+    assert_eq!(iana.state(), VacationState::Under(2));
+    assert_eq!(
+        iana.suggestion(),
+        vec![
+            (Month::September, 2),
+            (Month::October, 2),
+            (Month::November, 1),
+            (Month::December, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_vacation_ledger_over() {
+    let months = [
+        month_file("IANA", 7, Some(5)),
+        month_file("IANA", 8, Some(2)),
+    ];
+
+    let ledger = VacationLedger::new(&ledger_global(), Year::new(2022), &months)
+        .expect("ledger should be valid");
+
+    assert_eq!(ledger.accounts()[1].state(), VacationState::Over(1));
+    assert!(ledger.accounts()[1]
+        .suggestion()
+        .iter()
+        .all(|(_, months)| *months == 0));
+}
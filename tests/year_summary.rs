@@ -0,0 +1,66 @@
+//! Tests for the summary of all months in a year.
+
+use time_sheet::report::{TotalDuration, YearSummary};
+use time_sheet::time::{Month, Year};
+use time_sheet::working_duration;
+
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn summary(months: &[(usize, usize)]) -> YearSummary {
+    let global = common::parse_global(working_duration!(10:00), "");
+
+    YearSummary::from_configs(
+        Year::new(2022),
+        &common::make_worked_configs(&global, months),
+    )
+}
+
+#[test]
+fn test_running_balance() {
+    // the month of the other year is ignored
+    let summary = summary(&[(2022, 12), (2023, 1), (2022, 11)]);
+
+    let rows = summary.rows();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].month(), Month::November);
+    assert_eq!(rows[0].expected(), TotalDuration::from_mins(10 * 60));
+    assert_eq!(rows[0].worked(), TotalDuration::from_mins(8 * 60));
+    assert_eq!(rows[0].vacation(), TotalDuration::from_mins(0));
+    assert_eq!(rows[0].transfer_in(), TotalDuration::from_mins(60));
+    assert_eq!(rows[0].transfer_out(), TotalDuration::from_mins(30));
+    // 8:00 + 1:00 - 0:30 - 10:00
+    assert_eq!(rows[0].balance(), -90);
+    assert_eq!(rows[1].month(), Month::December);
+    assert_eq!(rows[1].balance(), -180);
+
+    let totals = summary.totals();
+    assert_eq!(totals.len(), 1);
+    assert_eq!(totals[0].worked(), TotalDuration::from_mins(16 * 60));
+    assert_eq!(totals[0].balance(), -180);
+}
+
+#[test]
+fn test_csv() {
+    let summary = summary(&[(2022, 11), (2022, 12)]);
+
+    assert_eq!(
+        summary.to_csv(),
+        concat!(
+            "Month,Department,Expected,Worked,Vacation,Transfer in,Transfer out,Balance\n",
+            "11,MENSA,10:00,08:00,00:00,01:00,00:30,-01:30\n",
+            "12,MENSA,10:00,08:00,00:00,01:00,00:30,-03:00\n",
+            "total,MENSA,20:00,16:00,00:00,02:00,01:00,-03:00\n",
+        )
+    );
+}
+
+#[test]
+fn test_latex_contains_rows() {
+    let latex = summary(&[(2022, 11)]).to_latex();
+
+    assert!(latex.contains("\\section*{Summary 2022}"));
+    assert!(latex.contains("11 & MENSA & 10:00 & 08:00 & 00:00 & 01:00 & 00:30 & -01:30 \\\\\n"));
+    assert!(latex.contains("Total & MENSA"));
+}